@@ -0,0 +1,58 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rustls;
+
+use rustls::cipher_suite::TLS13_CHACHA20_POLY1305_SHA256;
+use rustls::crypto::cipher::{AeadKey, BorrowedPlainMessage, Iv};
+use rustls::internal::msgs::deframer;
+use rustls::internal::msgs::message::Message;
+use rustls::internal::record_layer::RecordLayer;
+use rustls::{ContentType, ProtocolVersion, SupportedCipherSuite};
+use std::io;
+
+// The first byte of `data` is appended to the rest by the encrypter, so
+// `data` rotated left by one is the TLS1.3 inner plaintext: content, then
+// content type, then any zero padding.  Sealing it with the same key as
+// the decrypter means the records authenticate, and the unpadding code runs.
+fuzz_target!(|data: &[u8]| {
+    let (typ, payload) = match data.split_first() {
+        Some((typ, payload)) => (ContentType::from(*typ), payload),
+        None => return,
+    };
+
+    let suite = match TLS13_CHACHA20_POLY1305_SHA256 {
+        SupportedCipherSuite::Tls13(suite) => suite,
+        _ => unreachable!(),
+    };
+    let encrypter = suite
+        .aead_alg
+        .encrypter(AeadKey::from([0u8; 32]), Iv::new([0u8; 12]));
+    let sealed = match encrypter.encrypt(
+        BorrowedPlainMessage {
+            typ,
+            version: ProtocolVersion::TLSv1_2,
+            payload,
+        },
+        0,
+    ) {
+        Ok(sealed) => sealed.encode(),
+        Err(_) => return,
+    };
+
+    let mut dfm = deframer::MessageDeframer::default();
+    if dfm
+        .read(&mut io::Cursor::new(&sealed))
+        .is_err()
+    {
+        return;
+    }
+
+    let decrypter = suite
+        .aead_alg
+        .decrypter(AeadKey::from([0u8; 32]), Iv::new([0u8; 12]));
+    let mut rl = RecordLayer::with_decrypter(decrypter, 0);
+    while let Ok(Some(decrypted)) = dfm.pop(&mut rl, None) {
+        Message::try_from(decrypted.message).ok();
+    }
+});
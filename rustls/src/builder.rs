@@ -243,6 +243,27 @@ impl<S: ConfigSide> ConfigBuilder<S, WantsCipherSuites> {
         let default = self.state.0.default_cipher_suites();
         self.with_cipher_suites(default)
     }
+
+    /// Choose the FIPS-approved subset of the `CryptoProvider`'s default cipher suites.
+    ///
+    /// See [`SupportedCipherSuite::fips`].  This only restricts the algorithms: whether
+    /// their implementation is FIPS-validated is up to the provider ([`CryptoProvider::fips`]).
+    pub fn with_fips_cipher_suites(self) -> ConfigBuilder<S, WantsKxGroups> {
+        ConfigBuilder {
+            state: WantsKxGroups {
+                cipher_suites: self
+                    .state
+                    .0
+                    .default_cipher_suites()
+                    .iter()
+                    .filter(|cs| cs.fips())
+                    .copied()
+                    .collect(),
+                provider: self.state.0,
+            },
+            side: self.side,
+        }
+    }
 }
 
 /// Config builder state where the caller must supply key exchange groups.
@@ -277,6 +298,27 @@ impl<S: ConfigSide> ConfigBuilder<S, WantsKxGroups> {
         let default = self.state.provider.default_kx_groups();
         self.with_kx_groups(default)
     }
+
+    /// Choose the FIPS-approved subset of the default key exchange groups.
+    ///
+    /// See [`SupportedKxGroup::fips`].
+    pub fn with_fips_kx_groups(self) -> ConfigBuilder<S, WantsVersions> {
+        ConfigBuilder {
+            state: WantsVersions {
+                cipher_suites: self.state.cipher_suites,
+                kx_groups: self
+                    .state
+                    .provider
+                    .default_kx_groups()
+                    .iter()
+                    .filter(|kx| kx.fips())
+                    .copied()
+                    .collect(),
+                provider: self.state.provider,
+            },
+            side: self.side,
+        }
+    }
 }
 
 /// Config builder state where the caller must supply TLS protocol versions.
@@ -298,10 +340,24 @@ impl<S: ConfigSide> ConfigBuilder<S, WantsVersions> {
     }
 
     /// Use a specific set of protocol versions.
+    ///
+    /// This fails if `versions` is empty or names the same version more than once.
     pub fn with_protocol_versions(
         self,
         versions: &[&'static versions::SupportedProtocolVersion],
     ) -> Result<ConfigBuilder<S, WantsVerifier>, Error> {
+        if versions.is_empty() {
+            return Err(Error::General("no protocol versions configured".into()));
+        }
+
+        for (i, version) in versions.iter().enumerate() {
+            if versions[..i].contains(version) {
+                return Err(Error::General(
+                    "duplicate protocol version configured".into(),
+                ));
+            }
+        }
+
         let mut any_usable_suite = false;
         for suite in &self.state.cipher_suites {
             if versions.contains(&suite.version()) {
@@ -33,6 +33,10 @@ impl ConfigBuilder<ClientConfig, WantsVerifier> {
                 versions: self.state.versions,
                 verifier: Arc::new(webpki::WebPkiServerVerifier::new_without_revocation(
                     root_store,
+                    self.state
+                        .provider
+                        .signature_verification_algorithms()
+                        .unwrap_or(webpki::SUPPORTED_SIG_ALGS),
                 )),
             },
             side: PhantomData,
@@ -153,6 +157,7 @@ impl ConfigBuilder<ClientConfig, WantsClientCert> {
             key_log: Arc::new(NoKeyLog {}),
             enable_secret_extraction: false,
             enable_early_data: false,
+            enable_middlebox_compat: true,
         }
     }
 }
@@ -196,6 +196,19 @@ pub struct ClientConfig {
     ///
     /// The default is false.
     pub enable_early_data: bool,
+
+    /// Whether to use TLS 1.3 "middlebox compatibility mode".
+    ///
+    /// In this mode, described in [RFC 8446 appendix D.4], the client sends
+    /// a non-empty legacy session ID and a dummy ChangeCipherSpec record so
+    /// that the handshake looks more like a resumed TLS1.2 handshake to
+    /// middleboxes.  Disabling it saves a few bytes where no middleboxes
+    /// are present.  It is never used for QUIC.
+    ///
+    /// The default is true.
+    ///
+    /// [RFC 8446 appendix D.4]: https://datatracker.ietf.org/doc/html/rfc8446#appendix-D.4
+    pub enable_middlebox_compat: bool,
 }
 
 /// What mechanisms to support for resuming a TLS 1.2 session.
@@ -230,6 +243,7 @@ impl Clone for ClientConfig {
             key_log: Arc::clone(&self.key_log),
             enable_secret_extraction: self.enable_secret_extraction,
             enable_early_data: self.enable_early_data,
+            enable_middlebox_compat: self.enable_middlebox_compat,
         }
     }
 }
@@ -242,6 +256,7 @@ impl fmt::Debug for ClientConfig {
             .field("max_fragment_size", &self.max_fragment_size)
             .field("enable_sni", &self.enable_sni)
             .field("enable_early_data", &self.enable_early_data)
+            .field("enable_middlebox_compat", &self.enable_middlebox_compat)
             .finish_non_exhaustive()
     }
 }
@@ -269,6 +284,24 @@ impl ClientConfig {
         }
     }
 
+    /// Return `true` if connections made with this configuration only use
+    /// FIPS-approved algorithms, implemented by a FIPS-validated provider.
+    ///
+    /// This requires [`CryptoProvider::fips`], and that every configured
+    /// cipher suite and key exchange group is FIPS-approved (see
+    /// [`SupportedCipherSuite::fips`] and [`SupportedKxGroup::fips`]).
+    pub fn fips(&self) -> bool {
+        self.provider.fips()
+            && self
+                .cipher_suites
+                .iter()
+                .all(|cs| cs.fips())
+            && self
+                .kx_groups
+                .iter()
+                .all(|kx| kx.fips())
+    }
+
     /// We support a given TLS version if it's quoted in the configured
     /// versions *and* at least one ciphersuite for this version is
     /// also configured.
@@ -497,6 +530,14 @@ impl EarlyData {
         )
     }
 
+    fn outcome(&self) -> Option<bool> {
+        match self.state {
+            EarlyDataState::Disabled | EarlyDataState::Ready => None,
+            EarlyDataState::Accepted | EarlyDataState::AcceptedFinished => Some(true),
+            EarlyDataState::Rejected => Some(false),
+        }
+    }
+
     pub(super) fn enable(&mut self, max_data: usize) {
         assert_eq!(self.state, EarlyDataState::Disabled);
         self.state = EarlyDataState::Ready;
@@ -504,6 +545,11 @@ impl EarlyData {
     }
 
     pub(super) fn rejected(&mut self) {
+        // Early data that was never offered cannot be rejected.
+        if self.state == EarlyDataState::Disabled {
+            return;
+        }
+
         trace!("EarlyData rejected");
         self.state = EarlyDataState::Rejected;
     }
@@ -641,6 +687,25 @@ impl ClientConnection {
         self.inner.core.is_early_data_accepted()
     }
 
+    /// Returns whether the server accepted the early data we offered.
+    ///
+    /// This distinguishes the cases that [`ClientConnection::is_early_data_accepted`]
+    /// does not:
+    ///
+    /// - `None` if no early data was offered in this connection, or the server
+    ///   has not responded yet.
+    /// - `Some(true)` if the server accepted the early data.
+    /// - `Some(false)` if the server rejected it.  Any data written via
+    ///   [`ClientConnection::early_data`] was discarded by the server, and should
+    ///   be written again once the handshake completes if it is still wanted.
+    pub fn early_data_accepted(&self) -> Option<bool> {
+        self.inner
+            .core
+            .data
+            .early_data
+            .outcome()
+    }
+
     /// Extract secrets, so they can be used when configuring kTLS, for example.
     /// Should be used with care as it exposes secret key material.
     pub fn dangerous_extract_secrets(self) -> Result<ExtractedSecrets, Error> {
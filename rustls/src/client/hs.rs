@@ -143,11 +143,16 @@ pub(super) fn start_handshake(
         Some(session_id) => session_id,
         None if cx.common.is_quic() => SessionId::empty(),
         None if !config.supports_version(ProtocolVersion::TLSv1_3) => SessionId::empty(),
+        None if !config.enable_middlebox_compat => SessionId::empty(),
         None => SessionId::random(config.provider)?,
     };
 
     let random = Random::new(config.provider)?;
 
+    // Outside middlebox compatibility mode no fake ChangeCipherSpec is sent:
+    // treat it as already sent.
+    let sent_tls13_fake_ccs = !config.enable_middlebox_compat;
+
     Ok(emit_client_hello_for_retry(
         transcript_buffer,
         None,
@@ -160,7 +165,7 @@ pub(super) fn start_handshake(
             random,
             #[cfg(feature = "tls12")]
             using_ems: false,
-            sent_tls13_fake_ccs: false,
+            sent_tls13_fake_ccs,
             hello: ClientHelloDetails::new(),
             session_id,
             server_name,
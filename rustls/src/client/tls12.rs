@@ -106,6 +106,7 @@ mod server_hello {
             if let Some(resuming) = self.resuming_session {
                 if resuming.session_id == server_hello.session_id {
                     debug!("Server agreed to resume");
+                    cx.common.resumed = true;
 
                     // Is the server telling lies about the ciphersuite?
                     if resuming.suite() != suite {
@@ -774,6 +775,7 @@ impl State<ClientConnectionData> for ExpectServerDone {
         let kx = skxg
             .start()
             .map_err(|_| Error::FailedToGetRandomBytes)?;
+        cx.common.kx_group = Some(named_group);
 
         // 5b.
         let mut transcript = st.transcript;
@@ -1081,4 +1083,12 @@ impl State<ClientConnectionData> for ExpectTraffic {
         self.secrets
             .extract_secrets(Side::Client)
     }
+
+    fn send_key_update(
+        &mut self,
+        _common: &mut CommonState,
+        _request_update: bool,
+    ) -> Result<(), Error> {
+        Err(Error::KeyUpdateNotSupported)
+    }
 }
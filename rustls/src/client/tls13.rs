@@ -136,6 +136,7 @@ pub(super) fn handle_server_hello(
             }
 
             debug!("Resuming using PSK");
+            cx.common.resumed = true;
             // The key schedule has been initialized and set in fill_in_psk_binder()
         } else {
             return Err(PeerMisbehaved::SelectedUnofferedPsk.into());
@@ -150,6 +151,7 @@ pub(super) fn handle_server_hello(
         KeySchedulePreHandshake::new(suite)
     };
 
+    cx.common.kx_group = Some(their_key_share.group);
     let key_schedule =
         key_schedule_pre_handshake.into_handshake(our_key_share, &their_key_share.payload.0)?;
 
@@ -1066,6 +1068,16 @@ impl State<ClientConnectionData> for ExpectTraffic {
         self.key_schedule
             .extract_secrets(Side::Client)
     }
+
+    fn send_key_update(
+        &mut self,
+        common: &mut CommonState,
+        request_update: bool,
+    ) -> Result<(), Error> {
+        self.key_schedule
+            .send_key_update(common, request_update);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "quic")]
@@ -1093,4 +1105,13 @@ impl State<ClientConnectionData> for ExpectQuicTraffic {
         self.0
             .export_keying_material(output, label, context)
     }
+
+    fn send_key_update(
+        &mut self,
+        _common: &mut CommonState,
+        _request_update: bool,
+    ) -> Result<(), Error> {
+        // QUIC has no KeyUpdate message: see RFC 9001 section 6.
+        Err(Error::KeyUpdateNotSupported)
+    }
 }
@@ -4,7 +4,7 @@ use crate::error::{Error, InvalidMessage, PeerMisbehaved};
 use crate::log::{debug, warn};
 use crate::msgs::alert::AlertMessagePayload;
 use crate::msgs::base::Payload;
-use crate::msgs::enums::{AlertLevel, KeyUpdateRequest};
+use crate::msgs::enums::{AlertLevel, KeyUpdateRequest, NamedGroup};
 use crate::msgs::fragmenter::MessageFragmenter;
 #[cfg(feature = "quic")]
 use crate::msgs::message::MessagePayload;
@@ -29,17 +29,21 @@ pub struct CommonState {
     pub(crate) side: Side,
     pub(crate) record_layer: record_layer::RecordLayer,
     pub(crate) suite: Option<SupportedCipherSuite>,
+    pub(crate) kx_group: Option<NamedGroup>,
     pub(crate) alpn_protocol: Option<Vec<u8>>,
     pub(crate) aligned_handshake: bool,
     pub(crate) may_send_application_data: bool,
     pub(crate) may_receive_application_data: bool,
     pub(crate) early_traffic: bool,
     sent_fatal_alert: bool,
+    /// If we have queued a close_notify alert.
+    pub(crate) has_sent_close_notify: bool,
     /// If the peer has signaled end of stream.
     pub(crate) has_received_close_notify: bool,
     pub(crate) has_seen_eof: bool,
     pub(crate) received_middlebox_ccs: u8,
     pub(crate) peer_certificates: Option<Vec<CertificateDer<'static>>>,
+    pub(crate) resumed: bool,
     message_fragmenter: MessageFragmenter,
     pub(crate) received_plaintext: ChunkVecBuffer,
     sendable_plaintext: ChunkVecBuffer,
@@ -61,16 +65,19 @@ impl CommonState {
             side,
             record_layer: record_layer::RecordLayer::new(),
             suite: None,
+            kx_group: None,
             alpn_protocol: None,
             aligned_handshake: true,
             may_send_application_data: false,
             may_receive_application_data: false,
             early_traffic: false,
             sent_fatal_alert: false,
+            has_sent_close_notify: false,
             has_received_close_notify: false,
             has_seen_eof: false,
             received_middlebox_ccs: 0,
             peer_certificates: None,
+            resumed: false,
             message_fragmenter: MessageFragmenter::default(),
             received_plaintext: ChunkVecBuffer::new(Some(DEFAULT_RECEIVED_PLAINTEXT_LIMIT)),
             sendable_plaintext: ChunkVecBuffer::new(Some(DEFAULT_BUFFER_LIMIT)),
@@ -121,6 +128,16 @@ impl CommonState {
         self.peer_certificates.as_deref()
     }
 
+    /// Returns true if this connection resumed a previous session, rather than
+    /// performing a full handshake.
+    ///
+    /// This covers TLS1.2 session ID and ticket resumption, and TLS1.3 PSK
+    /// resumption.  It is only meaningful once the peer's hello has been
+    /// processed; check with [`CommonState::is_handshaking`] first.
+    pub fn is_resumed(&self) -> bool {
+        self.resumed
+    }
+
     /// Retrieves the protocol agreed with the peer via ALPN.
     ///
     /// A return value of `None` after handshake completion
@@ -137,6 +154,41 @@ impl CommonState {
         self.suite
     }
 
+    /// Retrieves the key exchange group agreed with the peer.
+    ///
+    /// This returns `None` until the key exchange group is agreed, and
+    /// remains `None` for a resumed TLS1.2 session, which performs no
+    /// key exchange.
+    pub fn negotiated_key_exchange_group(&self) -> Option<NamedGroup> {
+        self.kx_group
+    }
+
+    /// Returns how many more records can be encrypted under the current
+    /// traffic key before its confidentiality limit requires a key update.
+    ///
+    /// This allows a key update to be scheduled ahead of time, for example
+    /// during a quiet period on the connection.
+    ///
+    /// This returns `None` if no TLS1.3 cipher suite has been negotiated
+    /// yet, or if TLS1.2 is in use, since TLS1.2 has no in-band key update.
+    pub fn records_until_key_update(&self) -> Option<u64> {
+        match self.suite {
+            Some(SupportedCipherSuite::Tls13(suite)) if self.record_layer.is_encrypting() => Some(
+                self.record_layer
+                    .remaining_write_seq(suite.common.confidentiality_limit),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns counters of the records protected and unprotected so far
+    /// on this connection.
+    ///
+    /// See [`ConnectionStats`] for what is counted.
+    pub fn stats(&self) -> ConnectionStats {
+        self.record_layer.stats()
+    }
+
     /// Retrieves the protocol version agreed with the peer.
     ///
     /// This returns `None` until the version is agreed.
@@ -266,6 +318,10 @@ impl CommonState {
 
         // Refuse to wrap counter at all costs.  This
         // is basically untestable unfortunately.
+        //
+        // The record is dropped rather than reported: close_notify was
+        // queued above at the soft limit, after which `Writer` refuses
+        // application data, so only records sent while closing remain.
         if self.record_layer.encrypt_exhausted() {
             return;
         }
@@ -494,10 +550,20 @@ impl CommonState {
     /// [`Connection::write_tls`] call.  This informs the peer that the
     /// connection is being closed.
     ///
+    /// This only closes our side of the connection: data the peer sends
+    /// afterwards, until its own close_notify, can still be received and
+    /// read as usual.  This allows a graceful write shutdown.
+    ///
+    /// Once this has been called, writes to [`Connection::writer`] fail
+    /// with an `io::ErrorKind::BrokenPipe` error, rather than producing
+    /// data the peer is required to ignore.
+    ///
     /// [`Connection::write_tls`]: crate::Connection::write_tls
+    /// [`Connection::writer`]: crate::Connection::writer
     pub fn send_close_notify(&mut self) {
         debug!("Sending warning alert {:?}", AlertDescription::CloseNotify);
         self.send_warning_alert_no_log(AlertDescription::CloseNotify);
+        self.has_sent_close_notify = true;
     }
 
     fn send_warning_alert_no_log(&mut self, desc: AlertDescription) {
@@ -623,6 +689,54 @@ impl IoState {
     }
 }
 
+/// Counters of the records encrypted and decrypted on a connection.
+///
+/// These are returned from [`CommonState::stats`].  Only records protected by
+/// a traffic key are counted, so the unencrypted start of the handshake is not
+/// included.  Byte counts exclude the 5-byte record header.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConnectionStats {
+    pub(crate) records_sent: u64,
+    pub(crate) records_received: u64,
+    pub(crate) plaintext_bytes_sent: u64,
+    pub(crate) plaintext_bytes_received: u64,
+    pub(crate) ciphertext_bytes_sent: u64,
+    pub(crate) ciphertext_bytes_received: u64,
+}
+
+impl ConnectionStats {
+    /// How many records have been encrypted and sent.
+    pub fn records_sent(&self) -> u64 {
+        self.records_sent
+    }
+
+    /// How many records have been received and successfully decrypted.
+    pub fn records_received(&self) -> u64 {
+        self.records_received
+    }
+
+    /// How many plaintext bytes have been encrypted, including handshake
+    /// messages and alerts sent after encryption started.
+    pub fn plaintext_bytes_sent(&self) -> u64 {
+        self.plaintext_bytes_sent
+    }
+
+    /// How many plaintext bytes have been obtained by decryption.
+    pub fn plaintext_bytes_received(&self) -> u64 {
+        self.plaintext_bytes_received
+    }
+
+    /// How many bytes of encrypted record payload have been produced.
+    pub fn ciphertext_bytes_sent(&self) -> u64 {
+        self.ciphertext_bytes_sent
+    }
+
+    /// How many bytes of encrypted record payload have been decrypted.
+    pub fn ciphertext_bytes_received(&self) -> u64 {
+        self.ciphertext_bytes_received
+    }
+}
+
 pub(crate) trait State<Data>: Send + Sync {
     fn handle(
         self: Box<Self>,
@@ -642,6 +756,14 @@ pub(crate) trait State<Data>: Send + Sync {
     fn extract_secrets(&self) -> Result<PartiallyExtractedSecrets, Error> {
         Err(Error::HandshakeNotComplete)
     }
+
+    fn send_key_update(
+        &mut self,
+        _common: &mut CommonState,
+        _request_update: bool,
+    ) -> Result<(), Error> {
+        Err(Error::HandshakeNotComplete)
+    }
 }
 
 pub(crate) struct Context<'a, Data> {
@@ -83,6 +83,26 @@ impl Connection {
         }
     }
 
+    /// Sends a TLS1.3 `KeyUpdate` message and switches to new outgoing keys.
+    ///
+    /// See [`ConnectionCommon::queue_key_update()`] for more information.
+    pub fn queue_key_update(&mut self, request_update: bool) -> Result<(), Error> {
+        match self {
+            Self::Client(conn) => conn.queue_key_update(request_update),
+            Self::Server(conn) => conn.queue_key_update(request_update),
+        }
+    }
+
+    /// Queues a fatal alert with the given description, and stops the connection.
+    ///
+    /// See [`ConnectionCommon::abort_with_alert()`] for more information.
+    pub fn abort_with_alert(&mut self, desc: AlertDescription) {
+        match self {
+            Self::Client(conn) => conn.abort_with_alert(desc),
+            Self::Server(conn) => conn.abort_with_alert(desc),
+        }
+    }
+
     /// This function uses `io` to complete any outstanding IO for this connection.
     ///
     /// See [`ConnectionCommon::complete_io()`] for more information.
@@ -159,16 +179,7 @@ impl<'a> io::Read for Reader<'a> {
         let len = self.received_plaintext.read(buf)?;
 
         if len == 0 && !buf.is_empty() {
-            // No bytes available:
-            match (self.peer_cleanly_closed, self.has_seen_eof) {
-                // cleanly closed; don't care about TCP EOF: express this as Ok(0)
-                (true, _) => {}
-                // unclean closure
-                (false, true) => return Err(io::ErrorKind::UnexpectedEof.into()),
-                // connection still going, but need more data: signal `WouldBlock` so that
-                // the caller knows this
-                (false, false) => return Err(io::ErrorKind::WouldBlock.into()),
-            }
+            self.check_no_bytes_state()?;
         }
 
         Ok(len)
@@ -203,22 +214,67 @@ impl<'a> io::Read for Reader<'a> {
         let len = cursor.written() - before;
 
         if len == 0 && cursor.capacity() > 0 {
-            // No bytes available:
-            match (self.peer_cleanly_closed, self.has_seen_eof) {
-                // cleanly closed; don't care about TCP EOF: express this as Ok(0)
-                (true, _) => {}
-                // unclean closure
-                (false, true) => return Err(io::ErrorKind::UnexpectedEof.into()),
-                // connection still going, but need more data: signal `WouldBlock` so that
-                // the caller knows this
-                (false, false) => return Err(io::ErrorKind::WouldBlock.into()),
-            }
+            self.check_no_bytes_state()?;
         }
 
         Ok(())
     }
 }
 
+impl<'a> io::BufRead for Reader<'a> {
+    /// Obtain a view of the plaintext data received from the peer, without
+    /// consuming it.
+    ///
+    /// The returned slice is a non-empty prefix of the buffered plaintext
+    /// that has not yet been consumed.  Calling this function repeatedly
+    /// returns the same data: it is only released by a subsequent call to
+    /// [`consume`], or by reading it through [`std::io::Read`].
+    ///
+    /// This is useful for framing protocols where a length prefix must be
+    /// inspected before deciding whether enough data has arrived.
+    ///
+    /// The closure behaviour matches [`Reader::read`]: once all the pending
+    /// data has been consumed, this returns an empty slice if the peer closed
+    /// the TLS session cleanly, `Err(ErrorKind::UnexpectedEof.into())` if it
+    /// closed uncleanly, and `Err(ErrorKind::WouldBlock.into())` otherwise.
+    ///
+    /// [`consume`]: std::io::BufRead::consume
+    /// [`Reader::read`]: std::io::Read::read
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.received_plaintext.is_empty() {
+            self.check_no_bytes_state()?;
+        }
+
+        Ok(self
+            .received_plaintext
+            .chunk()
+            .unwrap_or_default())
+    }
+
+    /// Mark `amt` bytes of the data returned by [`fill_buf`] as consumed.
+    ///
+    /// [`fill_buf`]: std::io::BufRead::fill_buf
+    fn consume(&mut self, amt: usize) {
+        self.received_plaintext.consume(amt);
+    }
+}
+
+impl<'a> Reader<'a> {
+    /// Map the state of a reader that has no bytes available onto
+    /// the `io::Result` our callers should see.
+    fn check_no_bytes_state(&self) -> io::Result<()> {
+        match (self.peer_cleanly_closed, self.has_seen_eof) {
+            // cleanly closed; don't care about TCP EOF: express this as Ok(0)
+            (true, _) => Ok(()),
+            // unclean closure
+            (false, true) => Err(io::ErrorKind::UnexpectedEof.into()),
+            // connection still going, but need more data: signal `WouldBlock` so that
+            // the caller knows this
+            (false, false) => Err(io::ErrorKind::WouldBlock.into()),
+        }
+    }
+}
+
 /// Internal trait implemented by the [`ServerConnection`]/[`ClientConnection`]
 /// allowing them to be the subject of a [`Writer`].
 ///
@@ -232,10 +288,16 @@ pub(crate) trait PlaintextSink {
 
 impl<T> PlaintextSink for ConnectionCommon<T> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.has_sent_close_notify {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
         Ok(self.send_some_plaintext(buf))
     }
 
     fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        if self.has_sent_close_notify {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
         let mut sz = 0;
         for buf in bufs {
             sz += self.send_some_plaintext(buf);
@@ -249,6 +311,29 @@ impl<T> PlaintextSink for ConnectionCommon<T> {
 }
 
 /// A structure that implements [`std::io::Write`] for writing plaintext.
+///
+/// Plaintext written before the handshake completes is buffered, and sent as
+/// soon as the connection is allowed to send application data.  For a client,
+/// that is:
+///
+/// - TLS1.3: in the same flight as the client's `Finished` message.
+/// - TLS1.2 resumption: in the same flight as the client's `Finished` message.
+/// - TLS1.2 full handshake: after the server's `Finished` message has been
+///   received, which is one round trip later than TLS1.3.  rustls does not
+///   implement TLS False Start ([RFC 7918]), so this extra round trip cannot
+///   be avoided other than by resuming or using TLS1.3.
+///
+/// A server sends buffered data after the handshake completes, or straight
+/// after its own `Finished` message in TLS1.3 if
+/// [`ServerConfig::send_half_rtt_data`] is set and client authentication is
+/// not in use.
+///
+/// Data intended for TLS1.3 0-RTT is instead written with
+/// [`ClientConnection::early_data`].
+///
+/// [RFC 7918]: https://www.rfc-editor.org/rfc/rfc7918
+/// [`ServerConfig::send_half_rtt_data`]: crate::ServerConfig::send_half_rtt_data
+/// [`ClientConnection::early_data`]: crate::ClientConnection::early_data
 pub struct Writer<'a> {
     sink: &'a mut dyn PlaintextSink,
 }
@@ -273,6 +358,9 @@ impl<'a> io::Write for Writer<'a> {
     /// TLS handshake completes, and sends it as soon
     /// as it can.  See [`CommonState::set_buffer_limit`] to control
     /// the size of this buffer.
+    ///
+    /// After [`CommonState::send_close_notify`] this returns
+    /// `Err(ErrorKind::BrokenPipe.into())`.
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.sink.write(buf)
     }
@@ -479,6 +567,27 @@ impl<Data> ConnectionCommon<Data> {
         self.core.process_new_packets()
     }
 
+    /// Queues a fatal alert with the given description, and stops the connection.
+    ///
+    /// This allows rejecting the peer with a specific reason, for example
+    /// [`AlertDescription::AccessDenied`] after inspecting its certificate.
+    /// The alert is sent by the next [`Connection::write_tls`] call.  Afterwards
+    /// [`Connection::process_new_packets`] returns [`Error::AlertSent`], like it
+    /// does after any other fatal error.
+    ///
+    /// This does nothing if the connection has already failed.
+    pub fn abort_with_alert(&mut self, desc: AlertDescription) {
+        if self.core.state.is_err() {
+            return;
+        }
+
+        let err = self
+            .core
+            .common_state
+            .send_fatal_alert(desc, Error::AlertSent(desc));
+        self.core.state = Err(err);
+    }
+
     /// Read TLS content from `rd` into the internal buffer.
     ///
     /// Due to the internal buffering, `rd` can supply TLS messages in arbitrary-sized chunks (like
@@ -550,6 +659,27 @@ impl<Data> ConnectionCommon<Data> {
             .export_keying_material(output, label, context)
     }
 
+    /// Sends a TLS1.3 `KeyUpdate` message and switches to new keys for
+    /// data we send.
+    ///
+    /// If `request_update` is true, the peer is also asked to update the
+    /// keys it uses to send data to us.  Otherwise only our outgoing
+    /// direction is updated.
+    ///
+    /// The message is queued for sending: call [`Connection::write_tls`]
+    /// to send it.  Data written after this call is encrypted with the
+    /// new keys.
+    ///
+    /// This fails with [`Error::HandshakeNotComplete`] if called prior to the
+    /// handshake completing, and with [`Error::KeyUpdateNotSupported`] if
+    /// TLS1.2 was negotiated, since it has no key update mechanism.
+    ///
+    /// [`Connection::write_tls`]: crate::Connection::write_tls
+    pub fn queue_key_update(&mut self, request_update: bool) -> Result<(), Error> {
+        self.core
+            .queue_key_update(request_update)
+    }
+
     /// Extract secrets, so they can be used when configuring kTLS, for example.
     /// Should be used with care as it exposes secret key material.
     pub fn dangerous_extract_secrets(self) -> Result<ExtractedSecrets, Error> {
@@ -748,6 +878,13 @@ impl<Data> ConnectionCore<Data> {
             Err(e) => Err(e.clone()),
         }
     }
+
+    pub(crate) fn queue_key_update(&mut self, request_update: bool) -> Result<(), Error> {
+        match self.state.as_mut() {
+            Ok(st) => st.send_key_update(&mut self.common_state, request_update),
+            Err(e) => Err(e.clone()),
+        }
+    }
 }
 
 /// Data specific to the peer's side (client or server).
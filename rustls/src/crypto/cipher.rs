@@ -185,7 +185,10 @@ pub struct Nonce(pub [u8; NONCE_LEN]);
 impl Nonce {
     /// Combine an `Iv` and sequence number to produce a unique nonce.
     ///
-    /// This is `iv ^ seq` where `seq` is encoded as a 96-bit big-endian integer.
+    /// This is `iv ^ seq` where `seq` is encoded as a 96-bit big-endian integer,
+    /// as described in [RFC 8446 section 5.3](https://datatracker.ietf.org/doc/html/rfc8446#section-5.3).
+    /// The same construction is used for the TLS1.2 ChaCha20-Poly1305 suites
+    /// ([RFC 7905](https://datatracker.ietf.org/doc/html/rfc7905#section-2)).
     #[inline]
     pub fn new(iv: &Iv, seq: u64) -> Self {
         let mut nonce = Self([0u8; NONCE_LEN]);
@@ -209,7 +212,8 @@ pub const NONCE_LEN: usize = 12;
 
 /// Returns a TLS1.3 `additional_data` encoding.
 ///
-/// See RFC8446 s5.2 for the `additional_data` definition.
+/// See RFC8446 s5.2 for the `additional_data` definition.  `payload_len` is
+/// the length of the encrypted record body, including the AEAD tag.
 #[inline]
 pub fn make_tls13_aad(payload_len: usize) -> [u8; 5] {
     [
@@ -224,7 +228,8 @@ pub fn make_tls13_aad(payload_len: usize) -> [u8; 5] {
 
 /// Returns a TLS1.2 `additional_data` encoding.
 ///
-/// See RFC5246 s6.2.3.3 for the `additional_data` definition.
+/// See RFC5246 s6.2.3.3 for the `additional_data` definition.  Unlike TLS1.3,
+/// `len` is the length of the plaintext.
 #[inline]
 pub fn make_tls12_aad(
     seq: u64,
@@ -310,3 +315,47 @@ impl MessageDecrypter for InvalidMessageDecrypter {
         Err(Error::DecryptError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_is_iv_xor_seq() {
+        let iv = Iv::from([
+            0x5d, 0x31, 0x3e, 0xb2, 0x67, 0x12, 0x76, 0xee, 0x13, 0x00, 0x0b, 0x30,
+        ]);
+
+        assert_eq!(Nonce::new(&iv, 0).0, iv.0);
+        assert_eq!(
+            Nonce::new(&iv, 1).0,
+            [0x5d, 0x31, 0x3e, 0xb2, 0x67, 0x12, 0x76, 0xee, 0x13, 0x00, 0x0b, 0x31]
+        );
+        assert_eq!(
+            Nonce::new(&iv, 0x0102_0304_0506_0708).0,
+            [0x5d, 0x31, 0x3e, 0xb2, 0x66, 0x10, 0x75, 0xea, 0x16, 0x06, 0x0c, 0x38]
+        );
+        assert_eq!(
+            Nonce::new(&iv, u64::MAX).0,
+            [0x5d, 0x31, 0x3e, 0xb2, 0x98, 0xed, 0x89, 0x11, 0xec, 0xff, 0xf4, 0xcf]
+        );
+    }
+
+    #[test]
+    fn tls13_aad() {
+        assert_eq!(make_tls13_aad(0x1234), [0x17, 0x03, 0x03, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn tls12_aad() {
+        assert_eq!(
+            make_tls12_aad(
+                0x0102_0304_0506_0708,
+                ContentType::Handshake,
+                ProtocolVersion::TLSv1_2,
+                0x20
+            ),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x16, 0x03, 0x03, 0x00, 0x20]
+        );
+    }
+}
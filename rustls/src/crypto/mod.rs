@@ -1,4 +1,5 @@
 use crate::suites;
+use crate::webpki::WebPkiSupportedAlgorithms;
 use crate::{Error, NamedGroup};
 
 use alloc::boxed::Box;
@@ -43,6 +44,40 @@ pub trait CryptoProvider: Send + Sync + Debug + 'static {
 
     /// Return a safe set of supported key exchange groups to be used as the defaults.
     fn default_kx_groups(&self) -> &'static [&'static dyn SupportedKxGroup];
+
+    /// Return `true` if this provider is a FIPS-validated implementation.
+    ///
+    /// Such a provider's default cipher suites and key exchange groups should
+    /// only use FIPS-approved algorithms: see [`SupportedCipherSuite::fips`] and
+    /// [`SupportedKxGroup::fips`].
+    ///
+    /// The default implementation returns `false`.
+    ///
+    /// [`SupportedCipherSuite::fips`]: crate::SupportedCipherSuite::fips
+    fn fips(&self) -> bool {
+        false
+    }
+
+    /// Return the signature verification algorithms to use when verifying
+    /// certificates and handshake signatures.
+    ///
+    /// This is only used by the server certificate verifier that
+    /// `ConfigBuilder::with_root_certificates` builds for a client config using
+    /// this provider.  Restricting this set means certificate chains using any
+    /// other algorithm are rejected, even below the end-entity certificate.
+    ///
+    /// Verifiers built separately, with [`WebPkiServerVerifier::builder`] or
+    /// [`WebPkiClientVerifier::builder`], do not consult the provider: pass the
+    /// same set to their `with_signature_verification_algorithms` method.
+    ///
+    /// The default implementation returns `None`, which means the *ring*-based
+    /// defaults are used.
+    ///
+    /// [`WebPkiServerVerifier::builder`]: crate::client::WebPkiServerVerifier::builder
+    /// [`WebPkiClientVerifier::builder`]: crate::server::WebPkiClientVerifier::builder
+    fn signature_verification_algorithms(&self) -> Option<WebPkiSupportedAlgorithms> {
+        None
+    }
 }
 
 /// A supported key exchange group.
@@ -63,6 +98,26 @@ pub trait SupportedKxGroup: Send + Sync + Debug {
 
     /// Named group the SupportedKxGroup operates in.
     fn name(&self) -> NamedGroup;
+
+    /// Return `true` if this group is FIPS-approved.
+    ///
+    /// The default implementation approves the NIST P-256, P-384 and P-521
+    /// curves and the FFDHE groups, based on [`SupportedKxGroup::name`].  As
+    /// for cipher suites, whether the implementation is FIPS-validated is
+    /// decided by [`CryptoProvider::fips`].
+    fn fips(&self) -> bool {
+        matches!(
+            self.name(),
+            NamedGroup::secp256r1
+                | NamedGroup::secp384r1
+                | NamedGroup::secp521r1
+                | NamedGroup::FFDHE2048
+                | NamedGroup::FFDHE3072
+                | NamedGroup::FFDHE4096
+                | NamedGroup::FFDHE6144
+                | NamedGroup::FFDHE8192
+        )
+    }
 }
 
 /// An in-progress key exchange originating from a `SupportedKxGroup`.
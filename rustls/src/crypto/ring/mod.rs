@@ -1,4 +1,5 @@
 use crate::crypto::{CryptoProvider, SupportedKxGroup};
+use crate::enums::CipherSuite;
 use crate::rand::GetRandomFailed;
 use crate::suites::SupportedCipherSuite;
 
@@ -70,6 +71,16 @@ pub static ALL_CIPHER_SUITES: &[SupportedCipherSuite] = &[
     tls12::TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256,
 ];
 
+/// Look up the [`SupportedCipherSuite`] for `suite` among [`DEFAULT_CIPHER_SUITES`].
+///
+/// Returns `None` if *ring* does not support `suite`, or it is not enabled by default.
+pub fn cipher_suite_for(suite: CipherSuite) -> Option<SupportedCipherSuite> {
+    DEFAULT_CIPHER_SUITES
+        .iter()
+        .find(|scs| scs.suite() == suite)
+        .copied()
+}
+
 /// All defined key exchange groups supported by *ring* appear in this module.
 ///
 /// [`ALL_KX_GROUPS`] is provided as an array of all of these values.
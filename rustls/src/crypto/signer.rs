@@ -41,6 +41,10 @@ pub struct CertifiedKey {
     /// An optional OCSP response from the certificate issuer,
     /// attesting to its continued validity.
     pub ocsp: Option<Vec<u8>>,
+
+    /// OCSP responses for the intermediate certificates: see
+    /// [`CertifiedKey::with_intermediate_ocsp`].
+    pub(crate) intermediate_ocsp: Vec<Vec<u8>>,
 }
 
 impl CertifiedKey {
@@ -53,7 +57,25 @@ impl CertifiedKey {
             cert,
             key,
             ocsp: None,
+            intermediate_ocsp: Vec::new(),
+        }
+    }
+
+    /// Attach OCSP responses for the intermediate certificates.
+    ///
+    /// `responses[i]` is stapled to `cert[i + 1]`, so there must be exactly
+    /// one response per intermediate; use an empty response for an
+    /// intermediate that has none.  These are only sent in TLS1.3, where each
+    /// certificate in the chain can carry its own status.
+    pub fn with_intermediate_ocsp(mut self, responses: Vec<Vec<u8>>) -> Result<Self, Error> {
+        if responses.len() != self.cert.len().saturating_sub(1) {
+            return Err(Error::General(
+                "intermediate OCSP responses do not match certificate chain".into(),
+            ));
         }
+
+        self.intermediate_ocsp = responses;
+        Ok(self)
     }
 
     /// The end-entity certificate.
@@ -63,3 +85,45 @@ impl CertifiedKey {
             .ok_or(Error::NoCertificatesPresented)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoKey;
+
+    impl SigningKey for NoKey {
+        fn choose_scheme(&self, _offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+            None
+        }
+
+        fn algorithm(&self) -> SignatureAlgorithm {
+            SignatureAlgorithm::Anonymous
+        }
+    }
+
+    fn certified_key(chain_len: usize) -> CertifiedKey {
+        CertifiedKey::new(
+            vec![CertificateDer::from(vec![0u8]); chain_len],
+            Arc::new(NoKey),
+        )
+    }
+
+    #[test]
+    fn intermediate_ocsp_must_match_chain() {
+        let key = certified_key(3)
+            .with_intermediate_ocsp(vec![vec![1], vec![]])
+            .unwrap();
+        assert_eq!(key.intermediate_ocsp, vec![vec![1], vec![]]);
+
+        assert!(certified_key(3)
+            .with_intermediate_ocsp(vec![vec![1]])
+            .is_err());
+        assert!(certified_key(1)
+            .with_intermediate_ocsp(vec![vec![1]])
+            .is_err());
+        assert!(certified_key(1)
+            .with_intermediate_ocsp(vec![])
+            .is_ok());
+    }
+}
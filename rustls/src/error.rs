@@ -63,6 +63,10 @@ pub enum Error {
     /// We received a fatal alert.  This means the peer is unhappy.
     AlertReceived(AlertDescription),
 
+    /// We sent this fatal alert, because the application asked us to
+    /// abort the connection.
+    AlertSent(AlertDescription),
+
     /// We saw an invalid certificate.
     ///
     /// The contained error is from the certificate validation trait
@@ -94,6 +98,17 @@ pub enum Error {
     /// The `max_fragment_size` value supplied in configuration was too small,
     /// or too large.
     BadMaxFragmentSize,
+
+    /// The peer sent so many records that the read sequence number would
+    /// soon wrap, which would reuse AEAD nonces.  The peer should have closed
+    /// the connection or updated its keys long before this point.
+    SequenceOverflow,
+
+    /// A TLS1.3 key update was requested on a connection that cannot do one.
+    ///
+    /// TLS1.2 has no key update mechanism, and QUIC connections update their
+    /// packet protection keys through `quic::Secrets` instead.
+    KeyUpdateNotSupported,
 }
 
 /// A corrupt TLS message payload that resulted in an error.
@@ -495,6 +510,7 @@ impl fmt::Display for Error {
             Self::PeerIncompatible(ref why) => write!(f, "peer is incompatible: {:?}", why),
             Self::PeerMisbehaved(ref why) => write!(f, "peer misbehaved: {:?}", why),
             Self::AlertReceived(ref alert) => write!(f, "received fatal alert: {:?}", alert),
+            Self::AlertSent(ref alert) => write!(f, "sent fatal alert: {:?}", alert),
             Self::InvalidCertificate(ref err) => {
                 write!(f, "invalid peer certificate: {:?}", err)
             }
@@ -513,6 +529,10 @@ impl fmt::Display for Error {
             Self::BadMaxFragmentSize => {
                 write!(f, "the supplied max_fragment_size was too small or large")
             }
+            Self::SequenceOverflow => write!(f, "record sequence number exhausted"),
+            Self::KeyUpdateNotSupported => {
+                write!(f, "key updates are not supported by this connection")
+            }
             Self::General(ref err) => write!(f, "unexpected error: {}", err),
         }
     }
@@ -599,6 +619,7 @@ mod tests {
             super::PeerIncompatible::Tls12NotOffered.into(),
             super::PeerMisbehaved::UnsolicitedCertExtension.into(),
             Error::AlertReceived(AlertDescription::ExportRestriction),
+            Error::AlertSent(AlertDescription::AccessDenied),
             super::CertificateError::Expired.into(),
             Error::General("undocumented error".to_string()),
             Error::FailedToGetCurrentTime,
@@ -607,6 +628,8 @@ mod tests {
             Error::PeerSentOversizedRecord,
             Error::NoApplicationProtocol,
             Error::BadMaxFragmentSize,
+            Error::SequenceOverflow,
+            Error::KeyUpdateNotSupported,
             Error::InvalidCertRevocationList(CertRevocationListError::BadSignature),
         ];
 
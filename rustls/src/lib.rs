@@ -408,7 +408,7 @@ pub mod internal {
 pub use crate::builder::{
     ConfigBuilder, ConfigSide, WantsCipherSuites, WantsKxGroups, WantsVerifier, WantsVersions,
 };
-pub use crate::common_state::{CommonState, IoState, Side};
+pub use crate::common_state::{CommonState, ConnectionStats, IoState, Side};
 pub use crate::conn::{Connection, ConnectionCommon, Reader, SideData, Writer};
 pub use crate::enums::{
     AlertDescription, CipherSuite, ContentType, HandshakeType, ProtocolVersion, SignatureAlgorithm,
@@ -484,8 +484,8 @@ pub mod server {
     pub use crate::webpki::WebPkiClientVerifier;
     pub use crate::webpki::{ClientCertVerifierBuilder, VerifierBuilderError};
     pub use builder::WantsServerCert;
-    pub use handy::ResolvesServerCertUsingSni;
     pub use handy::{NoServerSessionStorage, ServerSessionMemoryCache};
+    pub use handy::{ResolvesServerCertUsingSigSchemes, ResolvesServerCertUsingSni};
     pub use server_conn::StoresServerSessions;
     pub use server_conn::{
         Accepted, Acceptor, ReadEarlyData, ServerConfig, ServerConnection, ServerConnectionData,
@@ -346,6 +346,57 @@ impl MessageDeframer {
         Ok(())
     }
 
+    /// Return the content type of the next complete record in our buffer,
+    /// without decrypting it or removing it from the buffer.
+    ///
+    /// Returns `None` if no complete record is buffered yet, or if the
+    /// buffered data is not a valid record.  Note that for encrypted TLS1.3
+    /// records this is the outer content type, which is always
+    /// `ApplicationData`.
+    pub fn peek_record_type(&self) -> Option<ContentType> {
+        if self.last_error.is_some() {
+            return None;
+        }
+
+        let start = match &self.joining_hs {
+            Some(meta) => meta.message.end,
+            None => 0,
+        };
+
+        let mut rd = codec::Reader::init(&self.buf[start..self.used]);
+        OpaqueMessage::read(&mut rd)
+            .ok()
+            .map(|m| m.typ)
+    }
+
+    /// Returns how many more bytes must be read to complete the next record.
+    ///
+    /// While fewer than the 5 bytes of a record header are buffered, this
+    /// only counts the bytes missing from the header, since the record
+    /// length is not yet known.  Returns `None` if a complete record is
+    /// already buffered, or if the deframer has failed.
+    pub fn bytes_needed(&self) -> Option<usize> {
+        if self.last_error.is_some() {
+            return None;
+        }
+
+        let start = match &self.joining_hs {
+            Some(meta) => meta.message.end,
+            None => 0,
+        };
+
+        let header_size = usize::from(OpaqueMessage::HEADER_SIZE);
+        let available = &self.buf[start..self.used];
+        if available.len() < header_size {
+            return Some(header_size - available.len());
+        }
+
+        let payload_len = usize::from(u16::from_be_bytes([available[3], available[4]]));
+        (header_size + payload_len)
+            .checked_sub(available.len())
+            .filter(|needed| *needed > 0)
+    }
+
     /// Returns true if we have messages for the caller
     /// to process, either whole messages in our output
     /// queue or partial messages in our buffer.
@@ -583,6 +634,42 @@ mod tests {
         assert!(d.last_error.is_none());
     }
 
+    #[test]
+    fn peek_record_type() {
+        let mut d = MessageDeframer::default();
+        assert_eq!(d.peek_record_type(), None);
+
+        input_bytes(&mut d, &FIRST_MESSAGE[..4]).unwrap();
+        assert_eq!(d.peek_record_type(), None);
+        input_bytes(&mut d, &FIRST_MESSAGE[4..]).unwrap();
+        assert_eq!(d.peek_record_type(), Some(ContentType::Handshake));
+        input_bytes(&mut d, SECOND_MESSAGE).unwrap();
+        assert_eq!(d.peek_record_type(), Some(ContentType::Handshake));
+
+        let mut rl = RecordLayer::new();
+        pop_first(&mut d, &mut rl);
+        assert_eq!(d.peek_record_type(), Some(ContentType::Alert));
+        pop_second(&mut d, &mut rl);
+        assert_eq!(d.peek_record_type(), None);
+    }
+
+    #[test]
+    fn bytes_needed() {
+        let mut d = MessageDeframer::default();
+        assert_eq!(d.bytes_needed(), Some(5));
+
+        input_bytes(&mut d, &FIRST_MESSAGE[..3]).unwrap();
+        assert_eq!(d.bytes_needed(), Some(2));
+        input_bytes(&mut d, &FIRST_MESSAGE[3..10]).unwrap();
+        assert_eq!(d.bytes_needed(), Some(FIRST_MESSAGE.len() - 10));
+        input_bytes(&mut d, &FIRST_MESSAGE[10..]).unwrap();
+        assert_eq!(d.bytes_needed(), None);
+
+        let mut rl = RecordLayer::new();
+        pop_first(&mut d, &mut rl);
+        assert_eq!(d.bytes_needed(), Some(5));
+    }
+
     #[test]
     fn check_whole() {
         let mut d = MessageDeframer::default();
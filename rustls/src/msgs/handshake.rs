@@ -2223,6 +2223,13 @@ impl HandshakeMessagePayload {
         }
     }
 
+    pub(crate) fn build_key_update_request() -> Self {
+        Self {
+            typ: HandshakeType::KeyUpdate,
+            payload: HandshakePayload::KeyUpdate(KeyUpdateRequest::UpdateRequested),
+        }
+    }
+
     pub(crate) fn get_encoding_for_binder_signing(&self) -> Vec<u8> {
         let mut ret = self.get_encoding();
 
@@ -11,6 +11,8 @@ use crate::msgs::handshake::HandshakeMessagePayload;
 
 use alloc::vec::Vec;
 
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
 #[derive(Debug)]
 pub enum MessagePayload {
     Alert(AlertMessagePayload),
@@ -207,25 +209,37 @@ impl OpaqueMessage {
     const MAX_PAYLOAD: u16 = 16384 + 2048;
 
     /// Content type, version and size.
-    const HEADER_SIZE: u16 = 1 + 2 + 2;
+    pub(crate) const HEADER_SIZE: u16 = 1 + 2 + 2;
 
     /// Maximum on-wire message size.
     pub const MAX_WIRE_SIZE: usize = (Self::MAX_PAYLOAD + Self::HEADER_SIZE) as usize;
 }
 
-/// `v` is a message payload, immediately post-decryption.  This function
-/// removes zero padding bytes, until a non-zero byte is encountered which is
-/// the content type, which is returned.  See RFC8446 s5.2.
+/// Remove the padding from a TLS1.3 inner plaintext, returning its content type.
+/// See RFC8446 s5.2.
+///
+/// This scans the whole of `v` regardless of where the content type byte is,
+/// so the time taken depends only on `v.len()` (which is public) and not on
+/// the length of the padding.
 ///
-/// ContentType(0) is returned if the message payload is empty or all zeroes.
+/// Returns `ContentType::Unknown(0)` if `v` contains only zeroes.
 fn unpad_tls13(v: &mut Vec<u8>) -> ContentType {
-    loop {
-        match v.pop() {
-            Some(0) => {}
-            Some(content_type) => return ContentType::from(content_type),
-            None => return ContentType::Unknown(0),
-        }
+    let mut content_type = 0u8;
+    let mut len = 0u64;
+
+    for (i, byte) in v.iter().enumerate() {
+        let non_zero = !byte.ct_eq(&0);
+        content_type.conditional_assign(byte, non_zero);
+        len.conditional_assign(&(i as u64), non_zero);
+    }
+
+    if content_type == 0 {
+        v.clear();
+        return ContentType::Unknown(0);
     }
+
+    v.truncate(len as usize);
+    ContentType::from(content_type)
 }
 
 impl From<Message> for PlainMessage {
@@ -310,6 +324,13 @@ impl Message {
             payload: MessagePayload::handshake(HandshakeMessagePayload::build_key_update_notify()),
         }
     }
+
+    pub fn build_key_update_request() -> Self {
+        Self {
+            version: ProtocolVersion::TLSv1_3,
+            payload: MessagePayload::handshake(HandshakeMessagePayload::build_key_update_request()),
+        }
+    }
 }
 
 /// Parses a plaintext message into a well-typed [`Message`].
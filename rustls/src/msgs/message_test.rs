@@ -1,4 +1,5 @@
-use crate::enums::{AlertDescription, HandshakeType};
+use crate::enums::{AlertDescription, ContentType, HandshakeType, ProtocolVersion};
+use crate::error::{Error, PeerMisbehaved};
 use crate::msgs::base::{PayloadU16, PayloadU24, PayloadU8};
 
 use super::base::Payload;
@@ -105,3 +106,37 @@ fn debug_payload() {
     assert_eq!("01020304", format!("{:?}", PayloadU16(vec![1, 2, 3, 4])));
     assert_eq!("01020304", format!("{:?}", PayloadU24(vec![1, 2, 3, 4])));
 }
+
+#[test]
+fn tls13_unpadding() {
+    fn unpad(body: &[u8]) -> Result<PlainMessage, Error> {
+        OpaqueMessage::new(
+            ContentType::ApplicationData,
+            ProtocolVersion::TLSv1_2,
+            body.to_vec(),
+        )
+        .into_tls13_unpadded_message()
+    }
+
+    for padding in [0, 1, 2, 255, 4096] {
+        let mut body = b"hello\x00world".to_vec();
+        body.push(ContentType::Handshake.get_u8());
+        body.extend(vec![0u8; padding]);
+
+        let m = unpad(&body).unwrap();
+        assert_eq!(m.typ, ContentType::Handshake);
+        assert_eq!(m.version, ProtocolVersion::TLSv1_3);
+        assert_eq!(m.payload.0, b"hello\x00world");
+    }
+
+    let m = unpad(b"\x17\x00\x00").unwrap();
+    assert_eq!(m.typ, ContentType::ApplicationData);
+    assert!(m.payload.0.is_empty());
+
+    for body in [&b""[..], &b"\x00"[..], &b"\x00\x00\x00\x00"[..]] {
+        assert_eq!(
+            unpad(body).unwrap_err(),
+            PeerMisbehaved::IllegalTlsInnerPlaintext.into()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common_state::ConnectionStats;
 use crate::crypto::cipher::{MessageDecrypter, MessageEncrypter};
 use crate::error::Error;
 use crate::msgs::message::{BorrowedPlainMessage, OpaqueMessage, PlainMessage};
@@ -31,6 +32,7 @@ pub struct RecordLayer {
     has_decrypted: bool,
     encrypt_state: DirectionState,
     decrypt_state: DirectionState,
+    stats: ConnectionStats,
 
     // Message encrypted with other keys may be encountered, so failures
     // should be swallowed by the caller.  This struct tracks the amount
@@ -49,10 +51,22 @@ impl RecordLayer {
             has_decrypted: false,
             encrypt_state: DirectionState::Invalid,
             decrypt_state: DirectionState::Invalid,
+            stats: ConnectionStats::default(),
             trial_decryption_len: None,
         }
     }
 
+    /// Create new record layer that decrypts incoming messages with `decrypter`,
+    /// starting from sequence number `read_seq`.
+    ///
+    /// This is intended for fuzzing and testing the decryption path.
+    pub fn with_decrypter(decrypter: Box<dyn MessageDecrypter>, read_seq: u64) -> Self {
+        let mut record_layer = Self::new();
+        record_layer.set_message_decrypter(decrypter);
+        record_layer.read_seq = read_seq;
+        record_layer
+    }
+
     /// Decrypt a TLS message.
     ///
     /// `encr` is a decoded message allegedly received from the peer.
@@ -79,6 +93,12 @@ impl RecordLayer {
         // failure has already happened.
         let want_close_before_decrypt = self.read_seq == SEQ_SOFT_LIMIT;
 
+        // We must not let our counter wrap, though: past this point the
+        // nonce would repeat.
+        if self.decrypt_exhausted() {
+            return Err(Error::SequenceOverflow);
+        }
+
         let encrypted_len = encr.payload().len();
         match self
             .message_decrypter
@@ -89,6 +109,9 @@ impl RecordLayer {
                 if !self.has_decrypted {
                     self.has_decrypted = true;
                 }
+                self.stats.records_received += 1;
+                self.stats.ciphertext_bytes_received += encrypted_len as u64;
+                self.stats.plaintext_bytes_received += plaintext.payload.0.len() as u64;
                 Ok(Some(Decrypted {
                     want_close_before_decrypt,
                     plaintext,
@@ -111,9 +134,15 @@ impl RecordLayer {
         assert!(!self.encrypt_exhausted());
         let seq = self.write_seq;
         self.write_seq += 1;
-        self.message_encrypter
+        let plaintext_len = plain.payload.len();
+        let encrypted = self
+            .message_encrypter
             .encrypt(plain, seq)
-            .unwrap()
+            .unwrap();
+        self.stats.records_sent += 1;
+        self.stats.plaintext_bytes_sent += plaintext_len as u64;
+        self.stats.ciphertext_bytes_sent += encrypted.payload().len() as u64;
+        encrypted
     }
 
     /// Prepare to use the given `MessageEncrypter` for future message encryption.
@@ -190,6 +219,21 @@ impl RecordLayer {
         self.write_seq >= SEQ_HARD_LIMIT
     }
 
+    /// Return true if we outright refuse to do anything with the
+    /// decryption key.
+    fn decrypt_exhausted(&self) -> bool {
+        self.read_seq >= SEQ_HARD_LIMIT
+    }
+
+    /// Return how many more messages may be encrypted with the current
+    /// encryption key before reaching `limit`, or the point where we
+    /// would refuse to continue.
+    pub(crate) fn remaining_write_seq(&self, limit: u64) -> u64 {
+        limit
+            .min(SEQ_SOFT_LIMIT)
+            .saturating_sub(self.write_seq)
+    }
+
     pub(crate) fn is_encrypting(&self) -> bool {
         self.encrypt_state == DirectionState::Active
     }
@@ -208,6 +252,10 @@ impl RecordLayer {
         self.read_seq
     }
 
+    pub(crate) fn stats(&self) -> ConnectionStats {
+        self.stats
+    }
+
     fn doing_trial_decryption(&mut self, requested: usize) -> bool {
         match self
             .trial_decryption_len
@@ -235,16 +283,42 @@ pub(crate) struct Decrypted {
 mod tests {
     use super::*;
 
+    struct PassThroughDecrypter;
+    impl MessageDecrypter for PassThroughDecrypter {
+        fn decrypt(&self, m: OpaqueMessage, _: u64) -> Result<PlainMessage, Error> {
+            Ok(m.into_plain_message())
+        }
+    }
+
     #[test]
-    fn test_has_decrypted() {
+    fn test_decrypt_refuses_at_seq_hard_limit() {
         use crate::{ContentType, ProtocolVersion};
 
-        struct PassThroughDecrypter;
-        impl MessageDecrypter for PassThroughDecrypter {
-            fn decrypt(&self, m: OpaqueMessage, _: u64) -> Result<PlainMessage, Error> {
-                Ok(m.into_plain_message())
-            }
-        }
+        let msg = || {
+            OpaqueMessage::new(
+                ContentType::ApplicationData,
+                ProtocolVersion::TLSv1_2,
+                vec![],
+            )
+        };
+        let mut record_layer =
+            RecordLayer::with_decrypter(Box::new(PassThroughDecrypter), SEQ_HARD_LIMIT - 1);
+        assert!(record_layer
+            .decrypt_incoming(msg())
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            record_layer
+                .decrypt_incoming(msg())
+                .err(),
+            Some(Error::SequenceOverflow)
+        );
+        assert_eq!(record_layer.read_seq(), SEQ_HARD_LIMIT);
+    }
+
+    #[test]
+    fn test_has_decrypted() {
+        use crate::{ContentType, ProtocolVersion};
 
         // A record layer starts out invalid, having never decrypted.
         let mut record_layer = RecordLayer::new();
@@ -292,4 +366,35 @@ mod tests {
         assert_eq!(record_layer.read_seq, 0);
         assert!(record_layer.has_decrypted());
     }
+
+    #[test]
+    fn test_with_decrypter() {
+        use crate::{ContentType, ProtocolVersion};
+
+        struct SeqCheckingDecrypter;
+        impl MessageDecrypter for SeqCheckingDecrypter {
+            fn decrypt(&self, m: OpaqueMessage, seq: u64) -> Result<PlainMessage, Error> {
+                assert_eq!(seq, 7);
+                Ok(m.into_plain_message())
+            }
+        }
+
+        let mut record_layer = RecordLayer::with_decrypter(Box::new(SeqCheckingDecrypter), 7);
+        assert!(matches!(record_layer.decrypt_state, DirectionState::Active));
+        assert!(matches!(
+            record_layer.encrypt_state,
+            DirectionState::Invalid
+        ));
+
+        let msg = OpaqueMessage::new(
+            ContentType::ApplicationData,
+            ProtocolVersion::TLSv1_2,
+            vec![0xC0, 0xFF, 0xEE],
+        );
+        record_layer
+            .decrypt_incoming(msg)
+            .unwrap();
+        assert_eq!(record_layer.read_seq, 8);
+        assert!(record_layer.has_decrypted());
+    }
 }
@@ -2,6 +2,8 @@ use crate::sign;
 
 use pki_types::CertificateDer;
 
+use alloc::vec::Vec;
+
 /// ActiveCertifiedKey wraps CertifiedKey and tracks OSCP and SCT state
 /// in a single handshake.
 pub(super) struct ActiveCertifiedKey<'a> {
@@ -33,4 +35,9 @@ impl<'a> ActiveCertifiedKey<'a> {
     pub(super) fn get_ocsp(&self) -> Option<&[u8]> {
         self.ocsp
     }
+
+    #[inline]
+    pub(super) fn get_intermediate_ocsp(&self) -> &[Vec<u8>] {
+        &self.key.intermediate_ocsp
+    }
 }
@@ -202,6 +202,41 @@ impl server::ResolvesServerCert for ResolvesServerCertUsingSni {
     }
 }
 
+/// Something that resolves to one of several cert chains/keys based
+/// on the signature schemes offered by the client.
+///
+/// This is useful for a server that holds, for example, both an RSA and
+/// an ECDSA certificate: each client is served the first certificate
+/// (in the order they were added) whose key can produce a signature
+/// the client is able to verify.
+pub struct ResolvesServerCertUsingSigSchemes {
+    keys: Vec<Arc<sign::CertifiedKey>>,
+}
+
+impl ResolvesServerCertUsingSigSchemes {
+    /// Create a new and empty (i.e., knows no certificates) resolver.
+    pub fn new() -> Self {
+        Self { keys: Vec::new() }
+    }
+
+    /// Add a new `sign::CertifiedKey`.
+    ///
+    /// Keys added earlier are preferred over those added later.
+    pub fn add(&mut self, ck: sign::CertifiedKey) {
+        self.keys.push(Arc::new(ck));
+    }
+}
+
+impl server::ResolvesServerCert for ResolvesServerCertUsingSigSchemes {
+    fn resolve(&self, client_hello: ClientHello) -> Option<Arc<sign::CertifiedKey>> {
+        let schemes = client_hello.signature_schemes();
+        self.keys
+            .iter()
+            .find(|ck| ck.key.choose_scheme(schemes).is_some())
+            .map(Arc::clone)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,7 +320,7 @@ mod tests {
     fn test_resolvesservercertusingsni_requires_sni() {
         let rscsni = ResolvesServerCertUsingSni::new();
         assert!(rscsni
-            .resolve(ClientHello::new(&None, &[], None, &[]))
+            .resolve(ClientHello::new(&None, &[], None, &[], None))
             .is_none());
     }
 
@@ -296,7 +331,7 @@ mod tests {
             .unwrap()
             .to_owned();
         assert!(rscsni
-            .resolve(ClientHello::new(&Some(name), &[], None, &[]))
+            .resolve(ClientHello::new(&Some(name), &[], None, &[], None))
             .is_none());
     }
 }
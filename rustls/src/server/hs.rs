@@ -319,6 +319,7 @@ impl ExpectClientHello {
                 &sig_schemes,
                 client_hello.get_alpn_extension(),
                 &client_hello.cipher_suites,
+                client_hello.get_versions_extension(),
             );
 
             let certkey = self
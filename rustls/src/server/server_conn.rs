@@ -120,6 +120,7 @@ pub struct ClientHello<'a> {
     signature_schemes: &'a [SignatureScheme],
     alpn: Option<&'a Vec<ProtocolName>>,
     cipher_suites: &'a [CipherSuite],
+    supported_versions: Option<&'a [ProtocolVersion]>,
 }
 
 impl<'a> ClientHello<'a> {
@@ -129,17 +130,20 @@ impl<'a> ClientHello<'a> {
         signature_schemes: &'a [SignatureScheme],
         alpn: Option<&'a Vec<ProtocolName>>,
         cipher_suites: &'a [CipherSuite],
+        supported_versions: Option<&'a [ProtocolVersion]>,
     ) -> Self {
         trace!("sni {:?}", server_name);
         trace!("sig schemes {:?}", signature_schemes);
         trace!("alpn protocols {:?}", alpn);
         trace!("cipher suites {:?}", cipher_suites);
+        trace!("supported versions {:?}", supported_versions);
 
         ClientHello {
             server_name,
             signature_schemes,
             alpn,
             cipher_suites,
+            supported_versions,
         }
     }
 
@@ -188,6 +192,14 @@ impl<'a> ClientHello<'a> {
     pub fn cipher_suites(&self) -> &[CipherSuite] {
         self.cipher_suites
     }
+
+    /// Get the protocol versions offered in the `supported_versions` extension.
+    ///
+    /// Returns `None` if the client did not include this extension, as is
+    /// the case for clients that predate TLS1.3.
+    pub fn supported_versions(&self) -> Option<&[ProtocolVersion]> {
+        self.supported_versions
+    }
 }
 
 /// Common configuration for a set of server sessions.
@@ -375,6 +387,24 @@ impl ServerConfig {
         }
     }
 
+    /// Return `true` if connections made with this configuration only use
+    /// FIPS-approved algorithms, implemented by a FIPS-validated provider.
+    ///
+    /// This requires [`CryptoProvider::fips`], and that every configured
+    /// cipher suite and key exchange group is FIPS-approved (see
+    /// [`SupportedCipherSuite::fips`] and [`SupportedKxGroup::fips`]).
+    pub fn fips(&self) -> bool {
+        self.provider.fips()
+            && self
+                .cipher_suites
+                .iter()
+                .all(|cs| cs.fips())
+            && self
+                .kx_groups
+                .iter()
+                .all(|kx| kx.fips())
+    }
+
     /// We support a given TLS version if it's quoted in the configured
     /// versions *and* at least one ciphersuite for this version is
     /// also configured.
@@ -400,6 +430,18 @@ impl<'a> ReadEarlyData<'a> {
     fn new(early_data: &'a mut EarlyDataState) -> Self {
         ReadEarlyData { early_data }
     }
+
+    /// How many more bytes of early data the client may send, within
+    /// [`ServerConfig::max_early_data_size`].
+    ///
+    /// This counts all early data received so far, whether or not it
+    /// has been read.  A client exceeding this limit causes the connection
+    /// to fail with [`PeerMisbehaved::TooMuchEarlyDataReceived`].
+    ///
+    /// [`PeerMisbehaved::TooMuchEarlyDataReceived`]: crate::PeerMisbehaved::TooMuchEarlyDataReceived
+    pub fn bytes_left(&self) -> usize {
+        self.early_data.bytes_left()
+    }
 }
 
 impl<'a> std::io::Read for ReadEarlyData<'a> {
@@ -678,6 +720,7 @@ impl Accepted {
             &self.sig_schemes,
             payload.get_alpn_extension(),
             &payload.cipher_suites,
+            payload.get_versions_extension(),
         )
     }
 
@@ -734,7 +777,10 @@ impl State<ServerConnectionData> for Accepting {
 
 pub(super) enum EarlyDataState {
     New,
-    Accepted(ChunkVecBuffer),
+    Accepted {
+        received: ChunkVecBuffer,
+        left: usize,
+    },
     Rejected,
 }
 
@@ -750,11 +796,21 @@ impl EarlyDataState {
     }
 
     pub(super) fn accept(&mut self, max_size: usize) {
-        *self = Self::Accepted(ChunkVecBuffer::new(Some(max_size)));
+        *self = Self::Accepted {
+            received: ChunkVecBuffer::new(Some(max_size)),
+            left: max_size,
+        };
     }
 
     fn was_accepted(&self) -> bool {
-        matches!(self, Self::Accepted(_))
+        matches!(self, Self::Accepted { .. })
+    }
+
+    fn bytes_left(&self) -> usize {
+        match self {
+            Self::Accepted { left, .. } => *left,
+            _ => 0,
+        }
     }
 
     pub(super) fn was_rejected(&self) -> bool {
@@ -763,7 +819,9 @@ impl EarlyDataState {
 
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         match self {
-            Self::Accepted(ref mut received) => received.read(buf),
+            Self::Accepted {
+                ref mut received, ..
+            } => received.read(buf),
             _ => Err(io::Error::from(io::ErrorKind::BrokenPipe)),
         }
     }
@@ -771,7 +829,9 @@ impl EarlyDataState {
     #[cfg(read_buf)]
     fn read_buf(&mut self, cursor: io::BorrowedCursor<'_>) -> io::Result<()> {
         match self {
-            Self::Accepted(ref mut received) => received.read_buf(cursor),
+            Self::Accepted {
+                ref mut received, ..
+            } => received.read_buf(cursor),
             _ => Err(io::Error::from(io::ErrorKind::BrokenPipe)),
         }
     }
@@ -779,8 +839,12 @@ impl EarlyDataState {
     pub(super) fn take_received_plaintext(&mut self, bytes: Payload) -> bool {
         let available = bytes.0.len();
         match self {
-            Self::Accepted(ref mut received) if received.apply_limit(available) == available => {
+            Self::Accepted {
+                ref mut received,
+                ref mut left,
+            } if available <= *left => {
                 received.append(bytes.0);
+                *left -= available;
                 true
             }
             _ => false,
@@ -858,4 +922,20 @@ mod tests {
             "Err(Kind(BrokenPipe))"
         );
     }
+
+    #[test]
+    fn early_data_limit_counts_bytes_already_read() {
+        let mut early_data = EarlyDataState::default();
+        early_data.accept(8);
+        assert_eq!(early_data.bytes_left(), 8);
+
+        assert!(early_data.take_received_plaintext(Payload::new(&b"hello"[..])));
+        assert_eq!(early_data.bytes_left(), 3);
+        assert_eq!(early_data.read(&mut [0u8; 5]).unwrap(), 5);
+        assert_eq!(early_data.bytes_left(), 3);
+
+        assert!(!early_data.take_received_plaintext(Payload::new(&b"hello"[..])));
+        assert!(early_data.take_received_plaintext(Payload::new(&b"abc"[..])));
+        assert_eq!(early_data.bytes_left(), 0);
+    }
 }
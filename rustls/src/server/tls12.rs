@@ -270,6 +270,7 @@ mod client_hello {
             resumedata: persist::ServerSessionValue,
         ) -> hs::NextStateOrError {
             debug!("Resuming connection");
+            cx.common.resumed = true;
 
             if resumedata.extended_ms && !self.using_ems {
                 return Err(cx.common.send_fatal_alert(
@@ -415,6 +416,7 @@ mod client_hello {
         let kx = selected_group
             .start()
             .map_err(|_| Error::FailedToGetRandomBytes)?;
+        common.kx_group = Some(selected_group.name());
         let secdh = ServerEcdhParams::new(&*kx);
 
         let mut msg = Vec::new();
@@ -950,4 +952,12 @@ impl State<ServerConnectionData> for ExpectTraffic {
         self.secrets
             .extract_secrets(Side::Server)
     }
+
+    fn send_key_update(
+        &mut self,
+        _common: &mut CommonState,
+        _request_update: bool,
+    ) -> Result<(), Error> {
+        Err(Error::KeyUpdateNotSupported)
+    }
 }
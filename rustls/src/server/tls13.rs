@@ -46,8 +46,8 @@ mod client_hello {
     use crate::enums::SignatureScheme;
     use crate::msgs::base::{Payload, PayloadU8};
     use crate::msgs::ccs::ChangeCipherSpecPayload;
-    use crate::msgs::enums::NamedGroup;
     use crate::msgs::enums::{Compression, PSKKeyExchangeMode};
+    use crate::msgs::enums::{ExtensionType, NamedGroup};
     use crate::msgs::handshake::CertReqExtension;
     use crate::msgs::handshake::CertificateEntry;
     use crate::msgs::handshake::CertificateExtension;
@@ -351,6 +351,7 @@ mod client_hello {
             }
 
             if let Some(ref resume) = resumedata {
+                cx.common.resumed = true;
                 cx.data.received_resumption_data = Some(resume.application_data.0.clone());
                 cx.common.peer_certificates = resume.client_cert_chain.clone();
             }
@@ -375,6 +376,8 @@ mod client_hello {
             }
 
             let mut ocsp_response = server_key.get_ocsp();
+            let intermediate_ocsp =
+                stapled_intermediate_ocsp(client_hello, server_key.get_intermediate_ocsp());
             let doing_early_data = emit_encrypted_extensions(
                 &mut self.transcript,
                 self.suite,
@@ -394,6 +397,7 @@ mod client_hello {
                     cx.common,
                     server_key.get_cert(),
                     ocsp_response,
+                    intermediate_ocsp,
                 );
                 emit_certificate_verify_tls13(
                     &mut self.transcript,
@@ -494,6 +498,7 @@ mod client_hello {
         let kx = kxgroup
             .start()
             .map_err(|_| Error::FailedToGetRandomBytes)?;
+        cx.common.kx_group = Some(share.group);
 
         let kse = KeyShareEntry::new(share.group, kx.pub_key());
         extensions.push(ServerExtension::KeyShare(kse));
@@ -695,6 +700,22 @@ mod client_hello {
         Ok(early_data)
     }
 
+    pub(super) fn certificate_req_extensions_tls13(
+        verifier: &dyn verify::ClientCertVerifier,
+    ) -> Vec<CertReqExtension> {
+        let schemes = verifier.supported_verify_schemes();
+        let mut extensions = vec![CertReqExtension::SignatureAlgorithms(schemes)];
+
+        // An empty certificate_authorities extension is not allowed, and
+        // omitting it tells the client that any issuer is acceptable.
+        let authorities = verifier.root_hint_subjects();
+        if !authorities.is_empty() {
+            extensions.push(CertReqExtension::AuthorityNames(authorities.to_vec()));
+        }
+
+        extensions
+    }
+
     fn emit_certificate_req_tls13(
         transcript: &mut HandshakeHash,
         cx: &mut ServerContext<'_>,
@@ -704,25 +725,11 @@ mod client_hello {
             return Ok(false);
         }
 
-        let mut cr = CertificateRequestPayloadTls13 {
+        let cr = CertificateRequestPayloadTls13 {
             context: PayloadU8::empty(),
-            extensions: Vec::new(),
+            extensions: certificate_req_extensions_tls13(config.verifier.as_ref()),
         };
 
-        let schemes = config
-            .verifier
-            .supported_verify_schemes();
-        cr.extensions
-            .push(CertReqExtension::SignatureAlgorithms(schemes.to_vec()));
-
-        cr.extensions
-            .push(CertReqExtension::AuthorityNames(
-                config
-                    .verifier
-                    .root_hint_subjects()
-                    .to_vec(),
-            ));
-
         let m = Message {
             version: ProtocolVersion::TLSv1_3,
             payload: MessagePayload::handshake(HandshakeMessagePayload {
@@ -737,12 +744,23 @@ mod client_hello {
         Ok(true)
     }
 
-    fn emit_certificate_tls13(
-        transcript: &mut HandshakeHash,
-        common: &mut CommonState,
+    /// Return the intermediate OCSP responses to staple: none, unless the
+    /// client sent the `status_request` extension.
+    pub(super) fn stapled_intermediate_ocsp<'a>(
+        client_hello: &ClientHelloPayload,
+        intermediate_ocsp: &'a [Vec<u8>],
+    ) -> &'a [Vec<u8>] {
+        match client_hello.find_extension(ExtensionType::StatusRequest) {
+            Some(_) => intermediate_ocsp,
+            None => &[],
+        }
+    }
+
+    pub(super) fn certificate_entries_tls13(
         cert_chain: &[CertificateDer<'static>],
         ocsp_response: Option<&[u8]>,
-    ) {
+        intermediate_ocsp: &[Vec<u8>],
+    ) -> Vec<CertificateEntry> {
         let mut cert_entries = vec![];
         for cert in cert_chain {
             let entry = CertificateEntry {
@@ -753,9 +771,22 @@ mod client_hello {
             cert_entries.push(entry);
         }
 
+        // Intermediate certificates follow the end-entity certificate.
+        for (entry, ocsp) in cert_entries
+            .iter_mut()
+            .skip(1)
+            .zip(intermediate_ocsp)
+        {
+            if !ocsp.is_empty() {
+                let cst = CertificateStatus::new(ocsp.clone());
+                entry
+                    .exts
+                    .push(CertificateExtension::CertificateStatus(cst));
+            }
+        }
+
         if let Some(end_entity_cert) = cert_entries.first_mut() {
-            // Apply OCSP response to first certificate (we don't support OCSP
-            // except for leaf certs).
+            // Apply OCSP response to first certificate.
             if let Some(ocsp) = ocsp_response {
                 let cst = CertificateStatus::new(ocsp.to_owned());
                 end_entity_cert
@@ -764,6 +795,17 @@ mod client_hello {
             }
         }
 
+        cert_entries
+    }
+
+    fn emit_certificate_tls13(
+        transcript: &mut HandshakeHash,
+        common: &mut CommonState,
+        cert_chain: &[CertificateDer<'static>],
+        ocsp_response: Option<&[u8]>,
+        intermediate_ocsp: &[Vec<u8>],
+    ) {
+        let cert_entries = certificate_entries_tls13(cert_chain, ocsp_response, intermediate_ocsp);
         let cert_body = CertificatePayloadTls13::new(cert_entries);
         let c = Message {
             version: ProtocolVersion::TLSv1_3,
@@ -1283,6 +1325,16 @@ impl State<ServerConnectionData> for ExpectTraffic {
         self.key_schedule
             .extract_secrets(Side::Server)
     }
+
+    fn send_key_update(
+        &mut self,
+        common: &mut CommonState,
+        request_update: bool,
+    ) -> Result<(), Error> {
+        self.key_schedule
+            .send_key_update(common, request_update);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "quic")]
@@ -1307,4 +1359,116 @@ impl State<ServerConnectionData> for ExpectQuicTraffic {
         self.key_schedule
             .export_keying_material(output, label, context)
     }
+
+    fn send_key_update(
+        &mut self,
+        _common: &mut CommonState,
+        _request_update: bool,
+    ) -> Result<(), Error> {
+        // QUIC has no KeyUpdate message: see RFC 9001 section 6.
+        Err(Error::KeyUpdateNotSupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::client_hello::{certificate_entries_tls13, stapled_intermediate_ocsp};
+    use crate::enums::CipherSuite;
+    use crate::msgs::enums::Compression;
+    use crate::msgs::handshake::{
+        CertificateExtension, CertificateStatusRequest, ClientExtension, ClientHelloPayload,
+        Random, SessionId,
+    };
+
+    use super::*;
+
+    fn client_hello(extensions: Vec<ClientExtension>) -> ClientHelloPayload {
+        ClientHelloPayload {
+            client_version: ProtocolVersion::TLSv1_2,
+            random: Random::from([0; 32]),
+            session_id: SessionId::empty(),
+            cipher_suites: vec![CipherSuite::TLS13_AES_128_GCM_SHA256],
+            compression_methods: vec![Compression::Null],
+            extensions,
+        }
+    }
+
+    fn ocsp_of(exts: &[CertificateExtension]) -> Vec<&[u8]> {
+        exts.iter()
+            .filter_map(|ext| match ext {
+                CertificateExtension::CertificateStatus(cs) => Some(cs.ocsp_response.0.as_slice()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intermediate_ocsp_is_stapled_to_intermediates() {
+        let chain = vec![
+            CertificateDer::from(&b"end-entity"[..]),
+            CertificateDer::from(&b"intermediate"[..]),
+            CertificateDer::from(&b"second intermediate"[..]),
+        ];
+        let intermediate_ocsp = vec![b"intermediate ocsp".to_vec(), vec![]];
+
+        let entries =
+            certificate_entries_tls13(&chain, Some(b"end-entity ocsp"), &intermediate_ocsp);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(ocsp_of(&entries[0].exts), vec![&b"end-entity ocsp"[..]]);
+        assert_eq!(ocsp_of(&entries[1].exts), vec![&b"intermediate ocsp"[..]]);
+        // an empty response adds no extension
+        assert!(entries[2].exts.is_empty());
+    }
+
+    #[test]
+    fn intermediate_ocsp_needs_status_request() {
+        let intermediate_ocsp = vec![b"intermediate ocsp".to_vec()];
+
+        let without = client_hello(vec![]);
+        assert!(stapled_intermediate_ocsp(&without, &intermediate_ocsp).is_empty());
+
+        let with = client_hello(vec![ClientExtension::CertificateStatusRequest(
+            CertificateStatusRequest::build_ocsp(),
+        )]);
+        assert_eq!(
+            stapled_intermediate_ocsp(&with, &intermediate_ocsp),
+            &intermediate_ocsp[..]
+        );
+    }
+
+    #[cfg(feature = "ring")]
+    #[test]
+    fn certificate_request_omits_empty_authorities() {
+        use super::client_hello::certificate_req_extensions_tls13;
+        use crate::msgs::handshake::CertReqExtension;
+        use crate::server::WebPkiClientVerifier;
+        use crate::RootCertStore;
+
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(CertificateDer::from(
+                &include_bytes!("../../../test-ca/rsa/ca.der")[..],
+            ))
+            .unwrap();
+        let roots = Arc::new(roots);
+        let has_authorities = |exts: &[CertReqExtension]| {
+            exts.iter()
+                .any(|ext| matches!(ext, CertReqExtension::AuthorityNames(_)))
+        };
+
+        let verifier = WebPkiClientVerifier::builder(Arc::clone(&roots))
+            .build()
+            .unwrap();
+        let exts = certificate_req_extensions_tls13(verifier.as_ref());
+        assert!(matches!(exts[0], CertReqExtension::SignatureAlgorithms(_)));
+        assert!(has_authorities(&exts));
+
+        let verifier = WebPkiClientVerifier::builder(roots)
+            .clear_root_hint_subjects()
+            .build()
+            .unwrap();
+        let exts = certificate_req_extensions_tls13(verifier.as_ref());
+        assert!(matches!(exts[0], CertReqExtension::SignatureAlgorithms(_)));
+        assert!(!has_authorities(&exts));
+    }
 }
@@ -18,6 +18,26 @@ pub struct CipherSuiteCommon {
 
     /// Which hash function the suite uses.
     pub hash_provider: &'static dyn crypto::hash::Hash,
+
+    /// Number of messages that can be safely encrypted with a single key of this type
+    ///
+    /// Once a `MessageEncrypter` produced for this suite has encrypted more than
+    /// `confidentiality_limit` messages, an attacker gains an advantage in distinguishing it
+    /// from an ideal pseudorandom permutation (PRP).
+    ///
+    /// This is to be set on the assumption that messages are maximally sized --
+    /// at least 2 ** 14 bytes for TCP-TLS and 2 ** 16 for QUIC.
+    pub confidentiality_limit: u64,
+
+    /// Number of messages that can be safely decrypted with a single key of this type
+    ///
+    /// Once a `MessageDecrypter` produced for this suite has failed to decrypt `integrity_limit`
+    /// messages, an attacker gains an advantage in forging messages.
+    ///
+    /// This is not relevant for TLS over TCP (which is implemented in this crate)
+    /// because a single failed decryption is fatal to the connection.  However,
+    /// this quantity is used by QUIC.
+    pub integrity_limit: u64,
 }
 
 /// A cipher suite supported by rustls.
@@ -50,6 +70,40 @@ impl SupportedCipherSuite {
         self.common().hash_provider
     }
 
+    /// Number of messages that can be safely encrypted with a single key
+    /// for this suite.
+    ///
+    /// See [`CipherSuiteCommon::confidentiality_limit`].
+    pub fn confidentiality_limit(&self) -> u64 {
+        self.common().confidentiality_limit
+    }
+
+    /// Number of failed decryptions that can be tolerated with a single key
+    /// for this suite.
+    ///
+    /// See [`CipherSuiteCommon::integrity_limit`].
+    pub fn integrity_limit(&self) -> u64 {
+        self.common().integrity_limit
+    }
+
+    /// Return `true` if this suite only uses FIPS-approved algorithms:
+    /// ECDHE key exchange and AES-GCM or AES-CCM encryption.
+    ///
+    /// This describes the algorithms, not their implementation; whether that
+    /// is FIPS-validated is up to the [`crypto::CryptoProvider`].
+    pub fn fips(&self) -> bool {
+        matches!(
+            self.suite(),
+            CipherSuite::TLS13_AES_128_GCM_SHA256
+                | CipherSuite::TLS13_AES_256_GCM_SHA384
+                | CipherSuite::TLS13_AES_128_CCM_SHA256
+                | CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256
+                | CipherSuite::TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384
+                | CipherSuite::TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256
+                | CipherSuite::TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384
+        )
+    }
+
     pub(crate) fn common(&self) -> &CipherSuiteCommon {
         match self {
             #[cfg(feature = "tls12")]
@@ -209,6 +263,28 @@ pub enum ConnectionTrafficSecrets {
         /// Initialization vector
         iv: Iv,
     },
+
+    /// Secrets for the AES_128_CCM AEAD algorithm
+    Aes128Ccm {
+        /// AEAD Key
+        key: AeadKey,
+        /// Initialization vector
+        iv: Iv,
+    },
+}
+
+impl ConnectionTrafficSecrets {
+    /// Return the key and initialization vector, whatever the AEAD algorithm.
+    ///
+    /// The algorithm itself is identified by the variant.
+    pub fn as_bytes(&self) -> (&[u8], &[u8]) {
+        match self {
+            Self::Aes128Gcm { key, iv }
+            | Self::Aes256Gcm { key, iv }
+            | Self::Chacha20Poly1305 { key, iv }
+            | Self::Aes128Ccm { key, iv } => (key.as_ref(), iv.as_ref()),
+        }
+    }
 }
 
 #[cfg(all(test, feature = "ring"))]
@@ -255,6 +331,22 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_aead_limits() {
+        assert_eq!(TLS13_AES_128_GCM_SHA256.confidentiality_limit(), 1 << 23);
+        assert_eq!(TLS13_AES_128_GCM_SHA256.integrity_limit(), 1 << 52);
+        assert_eq!(
+            TLS13_CHACHA20_POLY1305_SHA256.confidentiality_limit(),
+            u64::MAX
+        );
+        assert_eq!(TLS13_CHACHA20_POLY1305_SHA256.integrity_limit(), 1 << 36);
+
+        for suite in crypto::ring::ALL_CIPHER_SUITES {
+            assert!(suite.confidentiality_limit() > 0);
+            assert!(suite.integrity_limit() > 0);
+        }
+    }
+
     #[test]
     fn test_scs_is_debug() {
         println!("{:?}", crypto::ring::ALL_CIPHER_SUITES);
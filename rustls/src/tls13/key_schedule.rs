@@ -3,6 +3,7 @@ use crate::crypto::cipher::{AeadKey, Iv, MessageDecrypter};
 use crate::crypto::tls13::{expand, Hkdf, HkdfExpander, OkmBlock, OutputLengthError};
 use crate::crypto::{hash, hmac, ActiveKeyExchange};
 use crate::error::Error;
+use crate::msgs::message::Message;
 #[cfg(feature = "quic")]
 use crate::quic;
 use crate::suites::PartiallyExtractedSecrets;
@@ -470,6 +471,25 @@ impl KeyScheduleTraffic {
         self.ks.set_encrypter(&secret, common);
     }
 
+    /// Send a KeyUpdate message under the current keys, and then switch to
+    /// the next application traffic secret for our outgoing direction.
+    ///
+    /// If `request_update` is true, the peer is asked to update its keys too.
+    pub(crate) fn send_key_update(&mut self, common: &mut CommonState, request_update: bool) {
+        // Any KeyUpdate we queued in response to the peer was encrypted
+        // under the current keys, so must go out before ours.
+        common.perhaps_write_key_update();
+
+        let message = match request_update {
+            true => Message::build_key_update_request(),
+            false => Message::build_key_update_notify(),
+        };
+        common.send_msg_encrypt(message.into());
+
+        let secret = self.next_application_traffic_secret(common.side);
+        self.ks.set_encrypter(&secret, common);
+    }
+
     pub(crate) fn update_decrypter(&mut self, common: &mut CommonState) {
         let secret = self.next_application_traffic_secret(common.side.peer());
         self.ks.set_decrypter(&secret, common);
@@ -89,6 +89,14 @@ impl ChunkVecBuffer {
         self.chunks.pop_front()
     }
 
+    /// Inspect the first chunk from this object, without
+    /// consuming it.
+    pub(crate) fn chunk(&self) -> Option<&[u8]> {
+        self.chunks
+            .front()
+            .map(|ch| ch.as_slice())
+    }
+
     /// Read data out of this object, writing it into `buf`
     /// and returning how many bytes were written there.
     pub(crate) fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
@@ -119,7 +127,8 @@ impl ChunkVecBuffer {
         Ok(())
     }
 
-    fn consume(&mut self, mut used: usize) {
+    /// Discard `used` bytes from the front of this object.
+    pub(crate) fn consume(&mut self, mut used: usize) {
         while let Some(mut buf) = self.chunks.pop_front() {
             if used < buf.len() {
                 self.chunks
@@ -165,6 +174,23 @@ mod tests {
         assert_eq!(buf.to_vec(), b"helloworldhe".to_vec());
     }
 
+    #[test]
+    fn chunk_does_not_consume() {
+        let mut cvb = ChunkVecBuffer::new(None);
+        assert_eq!(cvb.chunk(), None);
+
+        cvb.append(b"hello".to_vec());
+        cvb.append(b"world".to_vec());
+        assert_eq!(cvb.chunk(), Some(&b"hello"[..]));
+        assert_eq!(cvb.chunk(), Some(&b"hello"[..]));
+        assert_eq!(cvb.len(), 10);
+
+        cvb.consume(3);
+        assert_eq!(cvb.chunk(), Some(&b"lo"[..]));
+        cvb.consume(2);
+        assert_eq!(cvb.chunk(), Some(&b"world"[..]));
+    }
+
     #[cfg(read_buf)]
     #[test]
     fn read_buf() {
@@ -208,7 +208,10 @@ impl Context {
     }
 
     fn bench(&self, count: usize) {
-        let verifier = WebPkiServerVerifier::new_without_revocation(self.roots.clone());
+        let verifier = WebPkiServerVerifier::new_without_revocation(
+            self.roots.clone(),
+            crate::webpki::SUPPORTED_SIG_ALGS,
+        );
         const OCSP_RESPONSE: &[u8] = &[];
         let mut times = Vec::new();
 
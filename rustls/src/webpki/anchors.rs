@@ -59,6 +59,28 @@ impl RootCertStore {
         (valid_count, invalid_count)
     }
 
+    /// Parse the given DER-encoded certificates and add all that can be parsed,
+    /// reporting those that could not.
+    ///
+    /// Like [`RootCertStore::add_parsable_certificates`], this adds every valid
+    /// certificate even if others are rejected.  Returns the index (within
+    /// `der_certs`) and the parse error of each rejected certificate.
+    pub fn add_certificates_reporting<'a>(
+        &mut self,
+        der_certs: impl IntoIterator<Item = CertificateDer<'a>>,
+    ) -> Vec<(usize, Error)> {
+        let mut errors = Vec::new();
+
+        for (i, der_cert) in der_certs.into_iter().enumerate() {
+            if let Err(err) = self.add(der_cert) {
+                debug!("certificate {} parsing failed: {:?}", i, err);
+                errors.push((i, err));
+            }
+        }
+
+        errors
+    }
+
     /// Add a single DER-encoded certificate to the store.
     ///
     /// This is suitable for a small set of root certificates that are expected to parse
@@ -91,6 +113,23 @@ impl RootCertStore {
             .collect()
     }
 
+    /// Remove every trust anchor whose subject is `subject`.
+    ///
+    /// `subject` is compared using the same encoding as the names returned by
+    /// [`RootCertStore::subjects`].  Returns `true` if any trust anchor was removed.
+    ///
+    /// Verifiers keep their own `Arc<RootCertStore>`, so this does not affect
+    /// verifiers (or configs) already built from this store.  To stop trusting
+    /// the anchor, build a new verifier and config from the updated store and
+    /// use that for new connections.
+    pub fn remove(&mut self, subject: &DistinguishedName) -> bool {
+        let before = self.roots.len();
+        self.roots.retain(|ta| {
+            DistinguishedName::in_sequence(ta.subject.as_ref()).as_ref() != subject.as_ref()
+        });
+        self.roots.len() != before
+    }
+
     /// Return true if there are no certificates.
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -107,3 +146,52 @@ impl Extend<TrustAnchor<'static>> for RootCertStore {
         self.roots.extend(iter);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CertificateError;
+
+    #[test]
+    fn remove_by_subject() {
+        let mut store = RootCertStore::empty();
+        store
+            .add(CertificateDer::from(
+                &include_bytes!("../../../test-ca/rsa/ca.der")[..],
+            ))
+            .unwrap();
+        store
+            .add(CertificateDer::from(
+                &include_bytes!("../../../test-ca/ecdsa/ca.der")[..],
+            ))
+            .unwrap();
+
+        let subjects = store.subjects();
+        assert!(store.remove(&subjects[0]));
+        assert!(!store.remove(&subjects[0]));
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.subjects()[0].as_ref(), subjects[1].as_ref());
+    }
+
+    #[test]
+    fn add_certificates_reporting() {
+        let mut store = RootCertStore::empty();
+        let errors = store.add_certificates_reporting([
+            CertificateDer::from(&include_bytes!("../../../test-ca/rsa/ca.der")[..]),
+            CertificateDer::from(&b"not a certificate"[..]),
+            CertificateDer::from(&include_bytes!("../../../test-ca/ecdsa/ca.der")[..]),
+            CertificateDer::from(&[][..]),
+        ]);
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0],
+            (1, Error::InvalidCertificate(CertificateError::BadEncoding))
+        ));
+        assert!(matches!(
+            errors[1],
+            (3, Error::InvalidCertificate(CertificateError::BadEncoding))
+        ));
+    }
+}
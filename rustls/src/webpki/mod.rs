@@ -19,6 +19,8 @@ pub use client_verifier::{ClientCertVerifierBuilder, WebPkiClientVerifier};
 pub use server_verifier::{ServerCertVerifierBuilder, WebPkiServerVerifier};
 
 pub use verify::WebPkiSupportedAlgorithms;
+#[cfg(feature = "ring")]
+pub(crate) use verify::SUPPORTED_SIG_ALGS;
 
 // Conditionally exported from crate.
 #[allow(unreachable_pub)]
@@ -153,13 +153,16 @@ impl WebPkiServerVerifier {
     /// Short-cut for creating a `WebPkiServerVerifier` that does not perform certificate revocation
     /// checking, avoiding the need to use a builder.
     #[cfg(feature = "ring")]
-    pub(crate) fn new_without_revocation(roots: impl Into<Arc<RootCertStore>>) -> Self {
+    pub(crate) fn new_without_revocation(
+        roots: impl Into<Arc<RootCertStore>>,
+        supported_algs: WebPkiSupportedAlgorithms,
+    ) -> Self {
         Self::new(
             roots,
             Vec::default(),
             RevocationCheckDepth::Chain,
             UnknownStatusPolicy::Allow,
-            SUPPORTED_SIG_ALGS,
+            supported_algs,
         )
     }
 
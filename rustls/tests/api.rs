@@ -3,7 +3,7 @@
 //! Assorted public API tests.
 use std::cell::RefCell;
 use std::fmt;
-use std::io::{self, IoSlice, Read, Write};
+use std::io::{self, BufRead, IoSlice, Read, Write};
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -28,7 +28,7 @@ use rustls::{
 };
 use rustls::{CipherSuite, ProtocolVersion, SignatureScheme};
 use rustls::{ClientConfig, ClientConnection};
-use rustls::{ConnectionTrafficSecrets, DistinguishedName};
+use rustls::{ConnectionStats, ConnectionTrafficSecrets, DistinguishedName};
 use rustls::{ServerConfig, ServerConnection};
 use rustls::{Stream, StreamOwned};
 
@@ -293,6 +293,40 @@ fn config_builder_for_server_rejects_incompatible_cipher_suites() {
     );
 }
 
+#[test]
+fn config_builder_rejects_empty_protocol_versions() {
+    assert_eq!(
+        ClientConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(&[])
+            .err(),
+        Some(Error::General("no protocol versions configured".into()))
+    );
+    assert_eq!(
+        ServerConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(&[])
+            .err(),
+        Some(Error::General("no protocol versions configured".into()))
+    );
+}
+
+#[test]
+fn config_builder_rejects_duplicate_protocol_versions() {
+    assert_eq!(
+        ClientConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(&[&rustls::version::TLS13, &rustls::version::TLS13])
+            .err(),
+        Some(Error::General(
+            "duplicate protocol version configured".into()
+        ))
+    );
+}
+
 #[test]
 fn buffered_client_data_sent() {
     let server_config = Arc::new(make_server_config(KeyType::Rsa));
@@ -393,12 +427,16 @@ fn client_can_get_server_cert_after_resumption() {
             do_handshake(&mut client, &mut server);
 
             let original_certs = client.peer_certificates();
+            assert!(!client.is_resumed());
+            assert!(!server.is_resumed());
 
             let (mut client, mut server) =
                 make_pair_for_configs(client_config.clone(), server_config.clone());
             do_handshake(&mut client, &mut server);
 
             let resumed_certs = client.peer_certificates();
+            assert!(client.is_resumed());
+            assert!(server.is_resumed());
 
             assert_eq!(original_certs, resumed_certs);
         }
@@ -596,6 +634,90 @@ fn client_close_notify() {
     }
 }
 
+#[test]
+fn peer_can_send_after_close_notify() {
+    for version in rustls::ALL_VERSIONS {
+        let client_config = make_client_config_with_versions(KeyType::Rsa, &[version]);
+        let (mut client, mut server) =
+            make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+        do_handshake(&mut client, &mut server);
+
+        client.send_close_notify();
+        transfer(&mut client, &mut server);
+        let io_state = server.process_new_packets().unwrap();
+        assert!(io_state.peer_has_closed());
+
+        server
+            .writer()
+            .write_all(b"response")
+            .unwrap();
+        server.send_close_notify();
+        transfer(&mut server, &mut client);
+        let io_state = client.process_new_packets().unwrap();
+        assert!(io_state.peer_has_closed());
+        check_read_and_close(&mut client.reader(), b"response");
+    }
+}
+
+#[test]
+fn write_after_close_notify_is_refused() {
+    for version in rustls::ALL_VERSIONS {
+        let client_config = make_client_config_with_versions(KeyType::Rsa, &[version]);
+        let (mut client, mut server) =
+            make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+        do_handshake(&mut client, &mut server);
+
+        client.send_close_notify();
+        let err = client
+            .writer()
+            .write(b"too late")
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        let err = client
+            .writer()
+            .write_vectored(&[IoSlice::new(b"too late")])
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        transfer(&mut client, &mut server);
+        let io_state = server.process_new_packets().unwrap();
+        assert!(io_state.peer_has_closed());
+        assert_eq!(io_state.plaintext_bytes_to_read(), 0);
+    }
+}
+
+#[test]
+fn application_can_abort_with_alert() {
+    let (mut client, mut server) = make_pair(KeyType::Rsa);
+    transfer(&mut client, &mut server);
+
+    server.abort_with_alert(AlertDescription::AccessDenied);
+    assert_eq!(
+        server.process_new_packets(),
+        Err(Error::AlertSent(AlertDescription::AccessDenied))
+    );
+
+    transfer(&mut server, &mut client);
+    assert_eq!(
+        client.process_new_packets(),
+        Err(Error::AlertReceived(AlertDescription::AccessDenied))
+    );
+
+    for version in rustls::ALL_VERSIONS {
+        let client_config = make_client_config_with_versions(KeyType::Rsa, &[version]);
+        let (mut client, mut server) =
+            make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+        do_handshake(&mut client, &mut server);
+
+        client.abort_with_alert(AlertDescription::UserCanceled);
+        transfer(&mut client, &mut server);
+        assert_eq!(
+            server.process_new_packets(),
+            Err(Error::AlertReceived(AlertDescription::UserCanceled))
+        );
+    }
+}
+
 #[test]
 fn server_closes_uncleanly() {
     let kt = KeyType::Rsa;
@@ -1892,6 +2014,72 @@ fn client_read_returns_wouldblock_when_no_data() {
                      Err(err) if err.kind() == io::ErrorKind::WouldBlock));
 }
 
+#[test]
+fn client_fill_buf_returns_wouldblock_when_no_data() {
+    let (mut client, _) = make_pair(KeyType::Rsa);
+    assert!(matches!(client.reader().fill_buf(),
+                     Err(err) if err.kind() == io::ErrorKind::WouldBlock));
+}
+
+#[test]
+fn client_can_peek_received_data_without_consuming() {
+    let (mut client, mut server) = make_pair(KeyType::Rsa);
+    do_handshake(&mut client, &mut server);
+
+    server
+        .writer()
+        .write_all(b"hello")
+        .unwrap();
+    server
+        .writer()
+        .write_all(b"world")
+        .unwrap();
+    transfer(&mut server, &mut client);
+    client.process_new_packets().unwrap();
+
+    // peeking is idempotent, and does not consume anything
+    let peeked = client
+        .reader()
+        .fill_buf()
+        .unwrap()
+        .to_vec();
+    assert!(!peeked.is_empty());
+    assert!(b"helloworld".starts_with(&peeked));
+    assert_eq!(client.reader().fill_buf().unwrap(), &peeked[..]);
+    assert_eq!(
+        client
+            .process_new_packets()
+            .unwrap()
+            .plaintext_bytes_to_read(),
+        10
+    );
+
+    // consume releases exactly the requested bytes
+    client.reader().consume(2);
+    assert_eq!(
+        client
+            .process_new_packets()
+            .unwrap()
+            .plaintext_bytes_to_read(),
+        8
+    );
+    let mut received = b"he".to_vec();
+    while received.len() < 10 {
+        let mut reader = client.reader();
+        let buf = reader.fill_buf().unwrap();
+        assert!(!buf.is_empty());
+        let len = buf.len();
+        received.extend_from_slice(buf);
+        reader.consume(len);
+    }
+    assert_eq!(received, b"helloworld");
+
+    server.send_close_notify();
+    transfer(&mut server, &mut client);
+    client.process_new_packets().unwrap();
+    assert_eq!(client.reader().fill_buf().unwrap(), b"");
+}
+
 #[test]
 fn new_server_returns_initial_io_state() {
     let (_, mut server) = make_pair(KeyType::Rsa);
@@ -2721,6 +2909,170 @@ fn test_tls13_exporter() {
     }
 }
 
+#[test]
+fn test_records_until_key_update() {
+    let (mut client, mut server) = make_pair(KeyType::Rsa);
+    assert_eq!(client.records_until_key_update(), None);
+    do_handshake(&mut client, &mut server);
+
+    let suite = client
+        .negotiated_cipher_suite()
+        .unwrap();
+    let before = client
+        .records_until_key_update()
+        .unwrap();
+    assert_eq!(before, suite.confidentiality_limit());
+
+    client
+        .writer()
+        .write_all(b"hello")
+        .unwrap();
+    assert_eq!(client.records_until_key_update(), Some(before - 1));
+}
+
+#[test]
+fn test_connection_stats() {
+    let (mut client, mut server) = make_pair(KeyType::Rsa);
+    assert_eq!(client.stats(), ConnectionStats::default());
+    do_handshake(&mut client, &mut server);
+
+    let client_before = client.stats();
+    let server_before = server.stats();
+    assert!(client_before.records_sent() > 0);
+    assert_eq!(
+        client_before.records_sent(),
+        server_before.records_received()
+    );
+    assert_eq!(
+        client_before.ciphertext_bytes_sent(),
+        server_before.ciphertext_bytes_received()
+    );
+
+    client
+        .writer()
+        .write_all(b"hello")
+        .unwrap();
+    transfer(&mut client, &mut server);
+    server.process_new_packets().unwrap();
+
+    let client_after = client.stats();
+    let server_after = server.stats();
+    assert_eq!(
+        client_after.records_sent(),
+        client_before.records_sent() + 1
+    );
+    assert_eq!(
+        client_after.plaintext_bytes_sent(),
+        client_before.plaintext_bytes_sent() + 5
+    );
+    assert!(client_after.ciphertext_bytes_sent() - client_before.ciphertext_bytes_sent() > 5);
+    assert_eq!(
+        server_after.records_received(),
+        server_before.records_received() + 1
+    );
+    assert_eq!(
+        server_after.plaintext_bytes_received(),
+        server_before.plaintext_bytes_received() + 5
+    );
+    assert_eq!(
+        server_after.ciphertext_bytes_received(),
+        client_after.ciphertext_bytes_sent()
+    );
+}
+
+#[cfg(feature = "tls12")]
+#[test]
+fn test_records_until_key_update_tls12() {
+    let client_config = make_client_config_with_versions(KeyType::Rsa, &[&rustls::version::TLS12]);
+    let (mut client, mut server) =
+        make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+    do_handshake(&mut client, &mut server);
+    assert_eq!(client.records_until_key_update(), None);
+    assert_eq!(server.records_until_key_update(), None);
+}
+
+fn do_key_update_test(request_update: bool) {
+    let client_config = make_client_config_with_versions(KeyType::Rsa, &[&rustls::version::TLS13]);
+    let (mut client, mut server) =
+        make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+    assert!(client
+        .queue_key_update(request_update)
+        .is_err());
+    do_handshake(&mut client, &mut server);
+
+    client
+        .writer()
+        .write_all(b"before")
+        .unwrap();
+    client
+        .queue_key_update(request_update)
+        .unwrap();
+    assert_eq!(
+        client.records_until_key_update(),
+        client
+            .negotiated_cipher_suite()
+            .map(|suite| suite.confidentiality_limit())
+    );
+    client
+        .writer()
+        .write_all(b" after")
+        .unwrap();
+    transfer(&mut client, &mut server);
+    server.process_new_packets().unwrap();
+    check_read(&mut server.reader(), b"before after");
+
+    server
+        .writer()
+        .write_all(b"reply")
+        .unwrap();
+    if request_update {
+        // the server responded with its own KeyUpdate, then sent one record
+        let limit = server
+            .negotiated_cipher_suite()
+            .unwrap()
+            .confidentiality_limit();
+        assert_eq!(server.records_until_key_update(), Some(limit - 1));
+    }
+    transfer(&mut server, &mut client);
+    client.process_new_packets().unwrap();
+    check_read(&mut client.reader(), b"reply");
+
+    client
+        .writer()
+        .write_all(b"again")
+        .unwrap();
+    transfer(&mut client, &mut server);
+    server.process_new_packets().unwrap();
+    check_read(&mut server.reader(), b"again");
+}
+
+#[test]
+fn test_queue_key_update_without_request() {
+    do_key_update_test(false);
+}
+
+#[test]
+fn test_queue_key_update_with_request() {
+    do_key_update_test(true);
+}
+
+#[cfg(feature = "tls12")]
+#[test]
+fn test_queue_key_update_fails_for_tls12() {
+    let client_config = make_client_config_with_versions(KeyType::Rsa, &[&rustls::version::TLS12]);
+    let (mut client, mut server) =
+        make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+    do_handshake(&mut client, &mut server);
+    assert_eq!(
+        client.queue_key_update(false),
+        Err(Error::KeyUpdateNotSupported)
+    );
+    assert_eq!(
+        server.queue_key_update(true),
+        Err(Error::KeyUpdateNotSupported)
+    );
+}
+
 #[test]
 fn test_tls13_exporter_maximum_output_length() {
     let client_config =
@@ -2828,6 +3180,24 @@ fn find_suite(suite: CipherSuite) -> SupportedCipherSuite {
     panic!("find_suite given unsupported suite");
 }
 
+#[test]
+fn cipher_suite_for_finds_default_suites() {
+    for scs in rustls::crypto::ring::DEFAULT_CIPHER_SUITES {
+        assert_eq!(
+            rustls::crypto::ring::cipher_suite_for(scs.suite()),
+            Some(*scs)
+        );
+    }
+}
+
+#[test]
+fn cipher_suite_for_unsupported_suite() {
+    assert!(rustls::crypto::ring::cipher_suite_for(CipherSuite::TLS_NULL_WITH_NULL_NULL).is_none());
+    assert!(
+        rustls::crypto::ring::cipher_suite_for(CipherSuite::TLS13_AES_128_CCM_SHA256).is_none()
+    );
+}
+
 static TEST_CIPHERSUITES: &[(&rustls::SupportedProtocolVersion, KeyType, CipherSuite)] = &[
     (
         &rustls::version::TLS13,
@@ -3508,6 +3878,8 @@ fn tls13_stateful_resumption() {
     // full handshake
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     let (full_c2s, full_s2c) = do_handshake(&mut client, &mut server);
+    assert!(!client.is_resumed());
+    assert!(!server.is_resumed());
     assert_eq!(storage.puts(), 4);
     assert_eq!(storage.gets(), 0);
     assert_eq!(storage.takes(), 0);
@@ -3521,6 +3893,8 @@ fn tls13_stateful_resumption() {
     // resumed
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     let (resume_c2s, resume_s2c) = do_handshake(&mut client, &mut server);
+    assert!(client.is_resumed());
+    assert!(server.is_resumed());
     assert!(resume_c2s > full_c2s);
     assert!(resume_s2c < full_s2c);
     assert_eq!(storage.puts(), 8);
@@ -3564,6 +3938,8 @@ fn tls13_stateless_resumption() {
     // full handshake
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     let (full_c2s, full_s2c) = do_handshake(&mut client, &mut server);
+    assert!(!client.is_resumed());
+    assert!(!server.is_resumed());
     assert_eq!(storage.puts(), 0);
     assert_eq!(storage.gets(), 0);
     assert_eq!(storage.takes(), 0);
@@ -3577,6 +3953,8 @@ fn tls13_stateless_resumption() {
     // resumed
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     let (resume_c2s, resume_s2c) = do_handshake(&mut client, &mut server);
+    assert!(client.is_resumed());
+    assert!(server.is_resumed());
     assert!(resume_c2s > full_c2s);
     assert!(resume_s2c < full_s2c);
     assert_eq!(storage.puts(), 0);
@@ -3628,6 +4006,7 @@ fn early_data_is_available_on_resumption() {
 
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     do_handshake(&mut client, &mut server);
+    assert_eq!(client.early_data_accepted(), None);
 
     let (mut client, mut server) = make_pair_for_arc_configs(&client_config, &server_config);
     assert!(client.early_data().is_some());
@@ -3651,8 +4030,17 @@ fn early_data_is_available_on_resumption() {
             .unwrap(),
         5
     );
+    assert_eq!(client.early_data_accepted(), None);
     do_handshake(&mut client, &mut server);
+    assert_eq!(client.early_data_accepted(), Some(true));
 
+    assert_eq!(
+        server
+            .early_data()
+            .expect("early_data didn't happen")
+            .bytes_left(),
+        1234 - 5
+    );
     let mut received_early_data = [0u8; 5];
     assert_eq!(
         server
@@ -3704,6 +4092,7 @@ fn early_data_can_be_rejected_by_server() {
     do_handshake(&mut client, &mut server);
 
     assert!(!client.is_early_data_accepted());
+    assert_eq!(client.early_data_accepted(), Some(false));
 }
 
 #[cfg(feature = "quic")]
@@ -4423,6 +4812,58 @@ fn test_client_does_not_offer_sha1() {
     }
 }
 
+#[test]
+fn test_client_without_middlebox_compat() {
+    use rustls::internal::msgs::{
+        codec::Codec, codec::Reader, handshake::HandshakePayload, message::MessagePayload,
+        message::OpaqueMessage,
+    };
+
+    for compat in [true, false] {
+        let mut client_config =
+            make_client_config_with_versions(KeyType::Rsa, &[&rustls::version::TLS13]);
+        client_config.enable_middlebox_compat = compat;
+        let (mut client, mut server) =
+            make_pair_for_configs(client_config, make_server_config(KeyType::Rsa));
+
+        let mut buf = [0u8; 262144];
+        let sz = client
+            .write_tls(&mut buf.as_mut())
+            .unwrap();
+        let msg = OpaqueMessage::read(&mut Reader::init(&buf[..sz])).unwrap();
+        let msg = Message::try_from(msg.into_plain_message()).unwrap();
+        let session_id = match msg.payload {
+            MessagePayload::Handshake { parsed, .. } => match parsed.payload {
+                HandshakePayload::ClientHello(ch) => ch.session_id,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        assert_eq!(session_id.get_encoding().len() > 1, compat);
+
+        server
+            .read_tls(&mut &buf[..sz])
+            .unwrap();
+        server.process_new_packets().unwrap();
+        transfer(&mut server, &mut client);
+        client.process_new_packets().unwrap();
+
+        // the client's second flight starts with the fake ChangeCipherSpec, if any
+        let sz = client
+            .write_tls(&mut buf.as_mut())
+            .unwrap();
+        assert_eq!(buf[0] == ContentType::ChangeCipherSpec.get_u8(), compat);
+
+        server
+            .read_tls(&mut &buf[..sz])
+            .unwrap();
+        server.process_new_packets().unwrap();
+        do_handshake(&mut client, &mut server);
+        assert!(!client.is_handshaking());
+        assert!(!server.is_handshaking());
+    }
+}
+
 #[test]
 fn test_client_config_keyshare() {
     let client_config = make_client_config_with_kx_groups(
@@ -4434,7 +4875,43 @@ fn test_client_config_keyshare() {
         &[rustls::crypto::ring::kx_group::SECP384R1],
     );
     let (mut client, mut server) = make_pair_for_configs(client_config, server_config);
+    assert_eq!(client.negotiated_key_exchange_group(), None);
+    assert_eq!(server.negotiated_key_exchange_group(), None);
+    do_handshake_until_error(&mut client, &mut server).unwrap();
+    assert_eq!(
+        client.negotiated_key_exchange_group(),
+        Some(rustls::NamedGroup::secp384r1)
+    );
+    assert_eq!(
+        server.negotiated_key_exchange_group(),
+        Some(rustls::NamedGroup::secp384r1)
+    );
+}
+
+#[cfg(feature = "tls12")]
+#[test]
+fn test_negotiated_key_exchange_group_tls12() {
+    let client_config = finish_client_config(
+        KeyType::Rsa,
+        ClientConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_kx_groups(&[rustls::crypto::ring::kx_group::X25519])
+            .with_protocol_versions(&[&rustls::version::TLS12])
+            .unwrap(),
+    );
+    let server_config =
+        make_server_config_with_kx_groups(KeyType::Rsa, &[rustls::crypto::ring::kx_group::X25519]);
+    let (mut client, mut server) = make_pair_for_configs(client_config, server_config);
     do_handshake_until_error(&mut client, &mut server).unwrap();
+    assert_eq!(client.protocol_version(), Some(ProtocolVersion::TLSv1_2));
+    assert_eq!(
+        client.negotiated_key_exchange_group(),
+        Some(rustls::NamedGroup::X25519)
+    );
+    assert_eq!(
+        server.negotiated_key_exchange_group(),
+        Some(rustls::NamedGroup::X25519)
+    );
 }
 
 #[test]
@@ -5052,6 +5529,10 @@ fn test_acceptor() {
     let accepted = acceptor.accept().unwrap().unwrap();
     let ch = accepted.client_hello();
     assert_eq!(ch.server_name(), Some("localhost"));
+    assert_eq!(
+        ch.supported_versions(),
+        Some(&[ProtocolVersion::TLSv1_3, ProtocolVersion::TLSv1_2][..])
+    );
 
     let server = accepted
         .into_connection(server_config)
@@ -5254,24 +5735,26 @@ fn test_secret_extraction_enabled() {
 
         // Comparing secrets for equality is something you should never have to
         // do in production code, so ConnectionTrafficSecrets doesn't implement
-        // PartialEq/Eq on purpose. Instead, we have to get creative.
-        fn explode_secrets(s: &ConnectionTrafficSecrets) -> (&[u8], &[u8]) {
-            match s {
-                ConnectionTrafficSecrets::Aes128Gcm { key, iv } => (key.as_ref(), iv.as_ref()),
-                ConnectionTrafficSecrets::Aes256Gcm { key, iv } => (key.as_ref(), iv.as_ref()),
-                ConnectionTrafficSecrets::Chacha20Poly1305 { key, iv } => {
-                    (key.as_ref(), iv.as_ref())
-                }
-                _ => panic!("unexpected secret type"),
-            }
-        }
-
+        // PartialEq/Eq on purpose. Instead, we compare the raw key and IV.
         fn assert_secrets_equal(
             (l_seq, l_sec): (u64, ConnectionTrafficSecrets),
             (r_seq, r_sec): (u64, ConnectionTrafficSecrets),
         ) {
             assert_eq!(l_seq, r_seq);
-            assert_eq!(explode_secrets(&l_sec), explode_secrets(&r_sec));
+            assert_eq!(l_sec.as_bytes(), r_sec.as_bytes());
+            assert!(matches!(
+                (l_sec, r_sec),
+                (
+                    ConnectionTrafficSecrets::Aes128Gcm { .. },
+                    ConnectionTrafficSecrets::Aes128Gcm { .. }
+                ) | (
+                    ConnectionTrafficSecrets::Aes256Gcm { .. },
+                    ConnectionTrafficSecrets::Aes256Gcm { .. }
+                ) | (
+                    ConnectionTrafficSecrets::Chacha20Poly1305 { .. },
+                    ConnectionTrafficSecrets::Chacha20Poly1305 { .. }
+                )
+            ));
         }
 
         assert_secrets_equal(client_secrets.tx, server_secrets.rx);
@@ -5447,6 +5930,187 @@ fn test_explicit_provider_selection() {
     do_handshake(&mut client, &mut server);
 }
 
+#[test]
+fn test_fips_follows_provider() {
+    use rustls::cipher_suite;
+    use rustls::crypto::ring::kx_group;
+
+    static FIPS_CIPHER_SUITES: &[SupportedCipherSuite] = &[
+        cipher_suite::TLS13_AES_256_GCM_SHA384,
+        cipher_suite::TLS13_AES_128_GCM_SHA256,
+    ];
+    static FIPS_KX_GROUPS: &[&dyn rustls::crypto::SupportedKxGroup] =
+        &[kx_group::SECP256R1, kx_group::SECP384R1];
+
+    #[derive(Debug)]
+    struct FipsProvider;
+
+    impl rustls::crypto::CryptoProvider for FipsProvider {
+        fn fill_random(&self, output: &mut [u8]) -> Result<(), rustls::crypto::GetRandomFailed> {
+            rustls::crypto::ring::RING.fill_random(output)
+        }
+
+        fn default_cipher_suites(&self) -> &'static [SupportedCipherSuite] {
+            FIPS_CIPHER_SUITES
+        }
+
+        fn default_kx_groups(&self) -> &'static [&'static dyn rustls::crypto::SupportedKxGroup] {
+            FIPS_KX_GROUPS
+        }
+
+        fn fips(&self) -> bool {
+            true
+        }
+    }
+
+    assert!(!rustls::crypto::ring::RING.fips());
+    assert!(!make_client_config(KeyType::Rsa).fips());
+    assert!(!make_server_config(KeyType::Rsa).fips());
+
+    let client_config = finish_client_config(
+        KeyType::Rsa,
+        ClientConfig::builder_with_provider(&FipsProvider).with_safe_defaults(),
+    );
+    assert!(client_config.fips());
+    let server_config = finish_server_config(
+        KeyType::Rsa,
+        ServerConfig::builder_with_provider(&FipsProvider).with_safe_defaults(),
+    );
+    assert!(server_config.fips());
+
+    // explicitly configured algorithms must be approved too
+    let client_config = finish_client_config(
+        KeyType::Rsa,
+        ClientConfig::builder_with_provider(&FipsProvider)
+            .with_cipher_suites(&[cipher_suite::TLS13_CHACHA20_POLY1305_SHA256])
+            .with_safe_default_kx_groups()
+            .with_safe_default_protocol_versions()
+            .unwrap(),
+    );
+    assert!(!client_config.fips());
+    let server_config = finish_server_config(
+        KeyType::Rsa,
+        ServerConfig::builder_with_provider(&FipsProvider)
+            .with_safe_default_cipher_suites()
+            .with_kx_groups(&[kx_group::X25519])
+            .with_safe_default_protocol_versions()
+            .unwrap(),
+    );
+    assert!(!server_config.fips());
+}
+
+#[test]
+fn config_builder_filters_fips_algorithms() {
+    #[derive(Debug)]
+    struct FipsRingDefaults;
+
+    impl rustls::crypto::CryptoProvider for FipsRingDefaults {
+        fn fill_random(&self, output: &mut [u8]) -> Result<(), rustls::crypto::GetRandomFailed> {
+            rustls::crypto::ring::RING.fill_random(output)
+        }
+
+        fn default_cipher_suites(&self) -> &'static [SupportedCipherSuite] {
+            rustls::crypto::ring::RING.default_cipher_suites()
+        }
+
+        fn default_kx_groups(&self) -> &'static [&'static dyn rustls::crypto::SupportedKxGroup] {
+            rustls::crypto::ring::RING.default_kx_groups()
+        }
+
+        fn fips(&self) -> bool {
+            true
+        }
+    }
+
+    // the defaults include ChaCha20-Poly1305 and X25519
+    let client_config = finish_client_config(
+        KeyType::Rsa,
+        ClientConfig::builder_with_provider(&FipsRingDefaults).with_safe_defaults(),
+    );
+    assert!(!client_config.fips());
+
+    let client_config = finish_client_config(
+        KeyType::Rsa,
+        ClientConfig::builder_with_provider(&FipsRingDefaults)
+            .with_fips_cipher_suites()
+            .with_fips_kx_groups()
+            .with_safe_default_protocol_versions()
+            .unwrap(),
+    );
+    assert!(client_config.fips());
+    let server_config = finish_server_config(
+        KeyType::Rsa,
+        ServerConfig::builder_with_provider(&FipsRingDefaults)
+            .with_fips_cipher_suites()
+            .with_fips_kx_groups()
+            .with_safe_default_protocol_versions()
+            .unwrap(),
+    );
+    assert!(server_config.fips());
+
+    let (mut client, mut server) = make_pair_for_configs(client_config, server_config);
+    do_handshake(&mut client, &mut server);
+    assert!(client
+        .negotiated_cipher_suite()
+        .unwrap()
+        .fips());
+}
+
+#[test]
+fn test_signature_verification_algorithms_follow_provider() {
+    static ECDSA_ONLY: rustls::WebPkiSupportedAlgorithms = rustls::WebPkiSupportedAlgorithms {
+        all: &[
+            webpki::ring::ECDSA_P256_SHA256,
+            webpki::ring::ECDSA_P256_SHA384,
+            webpki::ring::ECDSA_P384_SHA256,
+            webpki::ring::ECDSA_P384_SHA384,
+        ],
+        mapping: &[
+            (
+                SignatureScheme::ECDSA_NISTP384_SHA384,
+                &[webpki::ring::ECDSA_P384_SHA384],
+            ),
+            (
+                SignatureScheme::ECDSA_NISTP256_SHA256,
+                &[webpki::ring::ECDSA_P256_SHA256],
+            ),
+        ],
+    };
+
+    #[derive(Debug)]
+    struct EcdsaOnlyProvider;
+
+    impl rustls::crypto::CryptoProvider for EcdsaOnlyProvider {
+        fn fill_random(&self, output: &mut [u8]) -> Result<(), rustls::crypto::GetRandomFailed> {
+            rustls::crypto::ring::RING.fill_random(output)
+        }
+
+        fn default_cipher_suites(&self) -> &'static [SupportedCipherSuite] {
+            rustls::crypto::ring::RING.default_cipher_suites()
+        }
+
+        fn default_kx_groups(&self) -> &'static [&'static dyn rustls::crypto::SupportedKxGroup] {
+            rustls::crypto::ring::RING.default_kx_groups()
+        }
+
+        fn signature_verification_algorithms(&self) -> Option<rustls::WebPkiSupportedAlgorithms> {
+            Some(ECDSA_ONLY)
+        }
+    }
+
+    for (kt, works) in [(KeyType::Ecdsa, true), (KeyType::Rsa, false)] {
+        let client_config = finish_client_config(
+            kt,
+            ClientConfig::builder_with_provider(&EcdsaOnlyProvider).with_safe_defaults(),
+        );
+        let (mut client, mut server) = make_pair_for_configs(client_config, make_server_config(kt));
+        assert_eq!(
+            do_handshake_until_error(&mut client, &mut server).is_ok(),
+            works
+        );
+    }
+}
+
 #[derive(Debug)]
 struct FaultyRandomProvider {
     parent: &'static dyn rustls::crypto::CryptoProvider,
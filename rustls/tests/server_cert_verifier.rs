@@ -5,11 +5,12 @@
 mod common;
 use crate::common::{
     do_handshake, do_handshake_until_both_error, make_client_config_with_versions,
-    make_pair_for_arc_configs, make_server_config, ErrorFromPeer, ALL_KEY_TYPES,
+    make_pair_for_arc_configs, make_server_config, ErrorFromPeer, KeyType, ALL_KEY_TYPES,
 };
 use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
 use rustls::client::WebPkiServerVerifier;
-use rustls::DigitallySignedStruct;
+use rustls::server::ResolvesServerCertUsingSigSchemes;
+use rustls::{sign, DigitallySignedStruct};
 use rustls::{AlertDescription, Error, InvalidMessage, SignatureScheme};
 
 use pki_types::{CertificateDer, UnixTime};
@@ -155,6 +156,47 @@ fn client_can_override_certificate_verification_and_offer_no_signature_schemes()
     }
 }
 
+#[test]
+fn server_resolves_cert_the_client_can_verify() {
+    let mut resolver = ResolvesServerCertUsingSigSchemes::new();
+    for kt in [KeyType::Ecdsa, KeyType::Rsa] {
+        let key = sign::any_supported_type(&kt.get_key()).unwrap();
+        resolver.add(sign::CertifiedKey::new(kt.get_chain(), key));
+    }
+
+    let mut server_config = make_server_config(KeyType::Rsa);
+    server_config.cert_resolver = Arc::new(resolver);
+    let server_config = Arc::new(server_config);
+
+    let rsa_schemes = vec![
+        SignatureScheme::RSA_PSS_SHA256,
+        SignatureScheme::RSA_PKCS1_SHA256,
+    ];
+
+    for version in rustls::ALL_VERSIONS {
+        for (verifier, expected) in [
+            (MockServerVerifier::accepts_anything(), KeyType::Ecdsa),
+            (
+                MockServerVerifier::offers_signature_schemes(rsa_schemes.clone()),
+                KeyType::Rsa,
+            ),
+        ] {
+            let mut client_config = make_client_config_with_versions(KeyType::Rsa, &[version]);
+            client_config
+                .dangerous()
+                .set_certificate_verifier(Arc::new(verifier));
+
+            let (mut client, mut server) =
+                make_pair_for_arc_configs(&Arc::new(client_config), &server_config);
+            do_handshake(&mut client, &mut server);
+            assert_eq!(
+                client.peer_certificates().unwrap()[0],
+                expected.get_chain()[0]
+            );
+        }
+    }
+}
+
 pub struct MockServerVerifier {
     cert_rejection_error: Option<Error>,
     tls12_signature_error: Option<Error>,
@@ -251,8 +293,12 @@ impl MockServerVerifier {
     }
 
     pub fn offers_no_signature_schemes() -> Self {
+        Self::offers_signature_schemes(vec![])
+    }
+
+    pub fn offers_signature_schemes(signature_schemes: Vec<SignatureScheme>) -> Self {
         MockServerVerifier {
-            signature_schemes: vec![],
+            signature_schemes,
             ..Default::default()
         }
     }